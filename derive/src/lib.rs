@@ -0,0 +1,186 @@
+//! Derive macro for the `RadioOptions` trait of `radiors`.
+//!
+//! This crate is re-exported by `radiors` behind its `derive` feature and should not be
+//! used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use std::collections::HashMap;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr, Variant};
+
+/// Derives `RadioOptions` for an enum of unit variants.
+///
+/// Every variant becomes one option. Its value and label default to the variant name and
+/// can be customized with a `#[radio(...)]` attribute:
+///
+/// - `value = "..."`: the string form of the variant, used as the input `value`.
+/// - `label = "..."`: the text displayed next to the radio button.
+/// - `src = "..."`: an image URL displayed alongside the label.
+/// - `description = "..."`: secondary text displayed under the label.
+/// - `disabled`: renders the option as disabled.
+///
+/// A `Display` writing the value of each variant is derived as well, since `RadioOptions`
+/// requires it. Add `#[radio(no_display)]` to the enum to implement it yourself. Two
+/// variants with the same value are an error.
+#[proc_macro_derive(RadioOptions, attributes(radio))]
+pub fn derive_radio_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Options of a single variant, collected from its `#[radio(...)]` attributes.
+struct VariantOptions {
+    value: String,
+    value_span: Span,
+    label: String,
+    src: String,
+    description: String,
+    disabled: bool,
+}
+
+impl VariantOptions {
+    fn parse(variant: &Variant) -> syn::Result<Self> {
+        let name = variant.ident.to_string();
+        let mut options = Self {
+            value: name.clone(),
+            value_span: variant.ident.span(),
+            label: name,
+            src: String::new(),
+            description: String::new(),
+            disabled: false,
+        };
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("radio"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let field = if meta.path.is_ident("value") {
+                    let value = meta.value()?.parse::<LitStr>()?;
+                    options.value_span = value.span();
+                    options.value = value.value();
+                    return Ok(());
+                } else if meta.path.is_ident("label") {
+                    &mut options.label
+                } else if meta.path.is_ident("src") {
+                    &mut options.src
+                } else if meta.path.is_ident("description") {
+                    &mut options.description
+                } else if meta.path.is_ident("disabled") {
+                    options.disabled = true;
+                    return Ok(());
+                } else {
+                    return Err(
+                        meta.error("expected `value`, `label`, `src`, `description` or `disabled`")
+                    );
+                };
+                *field = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Whether `Display` is derived, unless the enum opts out with `#[radio(no_display)]`.
+fn derives_display(input: &DeriveInput) -> syn::Result<bool> {
+    let mut display = true;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("radio"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("no_display") {
+                display = false;
+                Ok(())
+            } else {
+                Err(meta.error("expected `no_display`"))
+            }
+        })?;
+    }
+    Ok(display)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`RadioOptions` can only be derived for enums",
+        ));
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut options = Vec::new();
+    let mut as_value = Vec::new();
+    let mut from_value = Vec::new();
+    let mut variants_by_value = HashMap::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`RadioOptions` variants cannot have fields",
+            ));
+        }
+        let name = &variant.ident;
+        let VariantOptions {
+            value,
+            value_span,
+            label,
+            src,
+            description,
+            disabled,
+        } = VariantOptions::parse(variant)?;
+        if let Some(first) = variants_by_value.insert(value.clone(), name) {
+            return Err(syn::Error::new(
+                value_span,
+                format!("`{name}` has the same value `{value}` as `{first}`"),
+            ));
+        }
+        options.push(quote! {
+            ::radiors::RadioOption::new(#ident::#name, #label)
+                .with_src(#src)
+                .with_description(#description)
+                .with_disabled(#disabled)
+        });
+        as_value.push(quote! { #ident::#name => #value });
+        from_value.push(quote! { #value => ::core::option::Option::Some(#ident::#name) });
+    }
+
+    let display = derives_display(&input)?.then(|| {
+        quote! {
+            impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(::radiors::RadioOptions::as_value(self))
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::radiors::RadioOptions for #ident #ty_generics #where_clause {
+            fn options() -> ::std::vec::Vec<::radiors::RadioOption<Self>> {
+                ::std::vec![#(#options),*]
+            }
+
+            fn as_value(&self) -> &'static str {
+                match *self {
+                    #(#as_value,)*
+                }
+            }
+
+            fn from_value(value: &str) -> ::core::option::Option<Self> {
+                match value {
+                    #(#from_value,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        #display
+    })
+}
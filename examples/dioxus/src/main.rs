@@ -20,10 +20,8 @@ fn app() -> Element {
 
 #[component]
 pub fn LandingPage() -> Element {
-    let os_selected = use_signal(|| "mac".to_string());
-    let browser_selected = use_signal(|| "chrome".to_string());
-
-    let onchange = |mut state: Signal<String>| move |value: String| state.set(value);
+    let os_selected = use_signal(|| "mac");
+    let browser_selected = use_signal(|| "chrome");
 
     rsx! {
         div {
@@ -39,16 +37,14 @@ pub fn LandingPage() -> Element {
                     pre {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
                         r##"Group {{
-    selected: selected.clone(),
-    onchange: |value| selected.set(value),
+    bind: selected,
     Radio {{ value: "mac", label: "Mac", input_style: "" }}
     Radio {{ value: "windows", label: "Windows", input_style: "" }}
     Radio {{ value: "linux", label: "Linux", input_style: "" }}
 }}"##
                     }
                     Group {
-                        selected: os_selected.clone(),
-                        onchange: onchange(os_selected.clone()),
+                        bind: os_selected,
                         Radio {
                             value: "mac",
                             label: "Mac",
@@ -74,8 +70,7 @@ pub fn LandingPage() -> Element {
                     pre {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
                         r##"Group {{
-    selected: os_selected.clone(),
-    onchange: |value| os_selected.set(value),
+    bind: os_selected,
     orientation: Orientation::Horizontal,
     Radio {{ value: "mac", label: "Mac", input_style: "" }}
     Radio {{ value: "windows", label: "Windows", input_style: "" }}
@@ -83,8 +78,7 @@ pub fn LandingPage() -> Element {
 }}"##
                     }
                     Group {
-                        selected: os_selected.clone(),
-                        onchange: onchange(os_selected.clone()),
+                        bind: os_selected,
                         orientation: Orientation::Horizontal,
                         Radio {
                             value: "mac",
@@ -111,16 +105,14 @@ pub fn LandingPage() -> Element {
                     pre {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
                         r##"Group {{
-    selected: browser_selected.clone(),
-    onchange: |value| browser_selected.set(value),
+    bind: browser_selected,
     Radio {{ value: "mac", label: "Mac", input_style: "", disabled: true }}
     Radio {{ value: "windows", label: "Windows", input_style: "", disabled: true }}
     Radio {{ value: "linux", label: "Linux", input_style: "", disabled: true }}
 }}"##
                     }
                     Group {
-                        selected: browser_selected.clone(),
-                        onchange: onchange(browser_selected.clone()),
+                        bind: browser_selected,
                         Radio {
                             value: "mac",
                             label: "Mac",
@@ -149,8 +141,7 @@ pub fn LandingPage() -> Element {
                     pre {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
                         r##"Group {{
-    selected: os_selected.clone(),
-    onchange: |value| os_selected.set(value),
+    bind: os_selected,
     orientation: Orientation::Horizontal,
     Radio {{
         value: "mac",
@@ -179,8 +170,7 @@ pub fn LandingPage() -> Element {
 }}"##
                     }
                     Group {
-                        selected: os_selected.clone(),
-                        onchange: onchange(os_selected.clone()),
+                        bind: os_selected,
                         orientation: Orientation::Horizontal,
                         Radio {
                             value: "mac",
@@ -189,7 +179,7 @@ pub fn LandingPage() -> Element {
                             class: "p-4 border-gray-400 border items-center rounded-lg cursor-pointer hover:shadow-md",
                             selected_class: "border-blue-500 bg-blue-100 text-blue-800",
                             image_class: "w-16",
-                            onclick: Callback::new(|value: String| tracing::info!("{} button has been selected", value))
+                            onclick: Callback::new(|value: &'static str| tracing::info!("{} button has been selected", value))
                         }
                         Radio {
                             value: "windows",
@@ -198,7 +188,7 @@ pub fn LandingPage() -> Element {
                             class: "p-4 border-gray-400 border items-center rounded-lg cursor-pointer hover:shadow-md",
                             selected_class: "border-blue-500 bg-blue-100 text-blue-800",
                             image_class: "w-16",
-                            onclick: Callback::new(|value: String| tracing::info!("{} button has been selected", value))
+                            onclick: Callback::new(|value: &'static str| tracing::info!("{} button has been selected", value))
                         }
                         Radio {
                             value: "linux",
@@ -207,7 +197,7 @@ pub fn LandingPage() -> Element {
                             class: "p-4 border-gray-400 border items-center rounded-lg cursor-pointer hover:shadow-md",
                             selected_class: "border-blue-500 bg-blue-100 text-blue-800",
                             image_class: "w-16",
-                            onclick: Callback::new(|value: String| tracing::info!("{} button has been selected", value))
+                            onclick: Callback::new(|value: &'static str| tracing::info!("{} button has been selected", value))
                         }
                     }
                 }
@@ -11,14 +11,9 @@ pub fn App() -> impl IntoView {
 
 #[component]
 pub fn Home() -> impl IntoView {
-    let os_selected = signal("mac".to_string());
-    let browser_selected = signal("chrome".to_string());
-    let size_selected = signal("medium".to_string());
-    let onchange = |state: (ReadSignal<String>, WriteSignal<String>)| {
-        Callback::from(move |value: String| {
-            state.1.set(value);
-        })
-    };
+    let os_selected = RwSignal::new("mac");
+    let browser_selected = RwSignal::new("chrome");
+    let size_selected = RwSignal::new("medium");
 
     view! {
         <div class="m-6 min-h-screen flex flex-col items-center justify-center">
@@ -32,8 +27,7 @@ pub fn Home() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
 >
     <Radio value="mac" label="Mac" input_style="" />
     <Radio value="windows" label="Windows" input_style="" />
@@ -41,8 +35,7 @@ pub fn Home() -> impl IntoView {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={os_selected.0.get()}
-                        onchange={onchange(os_selected)}
+                        bind={os_selected}
                     >
                         <Radio value="mac" label="Mac" input_style="" />
                         <Radio value="windows" label="Windows" input_style="" />
@@ -57,8 +50,7 @@ pub fn Home() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
 >
     <Radio value="mac" label="Mac" input_style="" />
@@ -67,8 +59,7 @@ pub fn Home() -> impl IntoView {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={os_selected.0.get()}
-                        onchange={onchange(os_selected)}
+                        bind={os_selected}
                         orientation={Orientation::Horizontal}
                     >
                         <Radio value="mac" label="Mac" input_style="" />
@@ -84,8 +75,7 @@ pub fn Home() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
 >
     <Radio value="chrome" label="Chrome" disabled=true input_style="" />
     <Radio value="firefox" label="Firefox" disabled=true input_style="" />
@@ -93,8 +83,7 @@ pub fn Home() -> impl IntoView {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={browser_selected.0.get()}
-                        onchange={onchange(browser_selected)}
+                        bind={browser_selected}
                     >
                         <Radio value="chrome" label="Chrome" disabled=true input_style="" />
                         <Radio value="firefox" label="Firefox" disabled=true input_style="" />
@@ -109,8 +98,7 @@ pub fn Home() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
     class="flex items-center gap-4 justify-center"
 >
@@ -141,8 +129,7 @@ pub fn Home() -> impl IntoView {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={os_selected.0.get()}
-                        onchange={onchange(os_selected)}
+                        bind={os_selected}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 justify-center"
                     >
@@ -180,8 +167,7 @@ pub fn Home() -> impl IntoView {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
     class="flex items-center gap-4"
 >
@@ -230,8 +216,7 @@ pub fn Home() -> impl IntoView {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={size_selected.0.get()}
-                        onchange={onchange(size_selected)}
+                        bind={size_selected}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 m-4"
                     >
@@ -258,8 +243,7 @@ pub fn Home() -> impl IntoView {
                         />
                     </Group>
                     <Group
-                        selected={size_selected.0.get()}
-                        onchange={onchange(size_selected)}
+                        bind={size_selected}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 m-4"
                     >
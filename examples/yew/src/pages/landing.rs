@@ -9,8 +9,6 @@ pub fn landing_page() -> Html {
     let size_selected = use_state(|| "medium".to_string());
     let color_selected = use_state(|| "primary".to_string());
     let advanced_color_selected = use_state(|| "primary".to_string());
-    let onchange =
-        |state: UseStateHandle<String>| Callback::from(move |value: String| state.set(value));
 
     html! {
         <div class="m-6 min-h-screen flex flex-col items-center justify-center">
@@ -23,8 +21,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
 >
     <Radio value="mac" label="Mac" input_style="" />
     <Radio value="windows" label="Windows" input_style="" />
@@ -32,8 +29,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*os_selected).clone()}
-                        onchange={onchange(os_selected.clone())}
+                        bind={os_selected.clone()}
                     >
                         <Radio value="mac" label="Mac" input_style="" />
                         <Radio value="windows" label="Windows" input_style="" />
@@ -47,8 +43,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
 >
     <Radio value="mac" label="Mac" input_style="" />
@@ -57,8 +52,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*os_selected).clone()}
-                        onchange={onchange(os_selected.clone())}
+                        bind={os_selected.clone()}
                         orientation={Orientation::Horizontal}
                     >
                         <Radio value="mac" label="Mac" input_style="" />
@@ -73,8 +67,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
 >
     <Radio value="chrome" label="Chrome" disabled=true input_style="" />
     <Radio value="firefox" label="Firefox" disabled=true input_style="" />
@@ -82,8 +75,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*browser_selected).clone()}
-                        onchange={onchange(browser_selected.clone())}
+                        bind={browser_selected.clone()}
                     >
                         <Radio value="chrome" label="Chrome" disabled=true input_style="" />
                         <Radio value="firefox" label="Firefox" disabled=true input_style="" />
@@ -99,8 +91,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
     class="flex items-center gap-4 justify-center"
 >
@@ -131,8 +122,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*os_selected).clone()}
-                        onchange={onchange(os_selected.clone())}
+                        bind={os_selected.clone()}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 justify-center"
                     >
@@ -169,8 +159,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
     class="flex items-center gap-4"
 >
@@ -219,8 +208,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*size_selected).clone()}
-                        onchange={onchange(size_selected.clone())}
+                        bind={size_selected.clone()}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 m-4"
                     >
@@ -247,8 +235,7 @@ pub fn landing_page() -> Html {
                         />
                     </Group>
                     <Group
-                        selected={(*size_selected).clone()}
-                        onchange={onchange(size_selected.clone())}
+                        bind={size_selected.clone()}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 m-4"
                     >
@@ -282,8 +269,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     orientation={Orientation::Horizontal}
     class="flex items-center gap-4"
 >
@@ -332,8 +318,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*advanced_color_selected).clone()}
-                        onchange={onchange(advanced_color_selected.clone())}
+                        bind={advanced_color_selected.clone()}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 m-4"
                     >
@@ -360,8 +345,7 @@ pub fn landing_page() -> Html {
                         />
                     </Group>
                     <Group
-                        selected={(*advanced_color_selected).clone()}
-                        onchange={onchange(advanced_color_selected.clone())}
+                        bind={advanced_color_selected.clone()}
                         orientation={Orientation::Horizontal}
                         class="flex items-center gap-4 m-4"
                     >
@@ -395,8 +379,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     class="flex items-center gap-4"
 >
     <Radio
@@ -416,8 +399,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*color_selected).clone()}
-                        onchange={onchange(color_selected.clone())}
+                        bind={color_selected.clone()}
                         class="flex items-center gap-4"
                     >
                         <Radio
@@ -445,8 +427,7 @@ pub fn landing_page() -> Html {
                         class="font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto"
                     >
                         { r#"<Group
-    selected={selected_state}
-    onchange={Callback::from(move |value: String| selected_state.set(value))}
+    bind={selected_state}
     class="flex items-center gap-4"
 >
     <Radio
@@ -466,8 +447,7 @@ pub fn landing_page() -> Html {
 </Group>"# }
                     </pre>
                     <Group
-                        selected={(*color_selected).clone()}
-                        onchange={onchange(color_selected.clone())}
+                        bind={color_selected.clone()}
                         class="flex items-center gap-4"
                     >
                         <Radio
@@ -1,28 +1,600 @@
+//! Framework-independent types and helpers shared by the Yew, Dioxus and Leptos adapters.
+//!
+//! # Selection
+//!
+//! A `Group` keeps its own selection, starting at `default_selected`, until its `selected`
+//! prop holds a value. From then on it is controlled: it shows `selected`, and a click only
+//! calls `onchange` until the parent feeds the new value back. Setting `selected` back to
+//! `None` clears the selection. A group with `bind` is controlled by that handle and writes
+//! every new selection into it before calling `onchange`.
+//!
+//! Resetting the group's `<form>` restores the initial selection through `onchange`. A
+//! group that started without a selection is cleared instead, without `onchange`, so a
+//! controlled group keeps its value until the parent clears it.
+
 #![allow(unused)]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+
 const FLEX_HORIZONTAL: &str = "display: flex; flex-direction: row; gap: 16px;";
 const FLEX_VERTICAL: &str = "display: flex; flex-direction: column; gap: 16px;";
-pub(crate) const HIDDEN_INPUT_STYLE: &str = "position: absolute; opacity: 0; pointer-events: none;";
+
+/// Whether built-in styles come from the classes of [`STYLESHEET`] instead of inline styles.
+const STYLESHEET_MODE: bool = cfg!(feature = "stylesheet");
+
+pub(crate) const HIDDEN_INPUT_STYLE: &str = if STYLESHEET_MODE {
+    ""
+} else {
+    "position: absolute; opacity: 0; pointer-events: none;"
+};
+pub(crate) const HIDDEN_INPUT_CLASS: &str = if STYLESHEET_MODE {
+    "radiors-radio__input"
+} else {
+    ""
+};
+pub(crate) const DESCRIPTION_STYLE: &str = if STYLESHEET_MODE {
+    ""
+} else {
+    "display: block; font-size: 0.875em; opacity: 0.75;"
+};
+pub(crate) const DESCRIPTION_CLASS: &str = if STYLESHEET_MODE {
+    "radiors-radio__description"
+} else {
+    ""
+};
+pub(crate) const CHECK_MARK_STYLE: &str = if STYLESHEET_MODE {
+    ""
+} else {
+    "position: absolute; top: var(--radiors-spacing-small, 7px); right: var(--radiors-spacing-small, 7px); \
+     display: inline-flex; align-items: center; justify-content: center; width: 1.25em; height: 1.25em; \
+     border-radius: 50%; font-size: 0.75em; line-height: 1; \
+     background-color: var(--radiors-primary, #007bff); color: var(--radiors-on-primary, #fff);"
+};
+pub(crate) const CHECK_MARK_CLASS: &str = if STYLESHEET_MODE {
+    "radiors-radio__check"
+} else {
+    ""
+};
+const CARD_STYLE: &str =
+    "position: relative; box-sizing: border-box; display: flex; flex-direction: column; \
+     gap: var(--radiors-spacing-xsmall, 5px); border: 1px solid rgba(128, 128, 128, 0.35); \
+     cursor: pointer;";
+const SEGMENT_STYLE: &str =
+    "position: relative; z-index: 1; text-align: center; white-space: nowrap; \
+     cursor: pointer; background-color: transparent; border-radius: 0; \
+     border-left: 1px solid var(--radiors-secondary, #6c757d); transition: color 0.2s ease;";
+const SEGMENT_SELECTED_STYLE: &str = "color: var(--radiors-on-segment, #ffffff);";
+const SEGMENTED_GROUP_STYLE: &str =
+    "position: relative; display: inline-grid; grid-auto-flow: column; \
+     grid-auto-columns: 1fr; gap: 0; overflow: hidden; \
+     border: 1px solid var(--radiors-secondary, #6c757d); border-left: none; \
+     border-radius: var(--radiors-radius, 0);";
+const SEGMENT_HIGHLIGHT_STYLE: &str = "position: absolute; top: 0; bottom: 0; \
+     transition: left 0.2s ease, width 0.2s ease; \
+     background-color: var(--radiors-segment, var(--radiors-primary, #007bff));";
+pub(crate) const SEGMENT_HIGHLIGHT_CLASS: &str = if STYLESHEET_MODE {
+    "radiors-group__highlight"
+} else {
+    ""
+};
+const CARD_SELECTED_STYLE: &str = "border-color: var(--radiors-primary, #007bff); \
+     outline: 1px solid var(--radiors-primary, #007bff); outline-offset: -2px;";
+
+/// The stylesheet matching the classes emitted with the `stylesheet` feature.
+///
+/// It defines the `--radiors-*` variables of `Theme::light()` on `:root`, switches to
+/// `Theme::dark()` under `prefers-color-scheme: dark`, and styles the `radiors-*`
+/// classes. Serve the bundled `radiors.css` file directly, or write this constant out
+/// from a build script, followed by `Theme::to_css` rules for your own themes.
+pub const STYLESHEET: &str = include_str!("../radiors.css");
+
+/// A value that can be bound to a radio button.
+///
+/// `Group` and `Radio` are generic over their value type, so a group can be bound
+/// directly to an enum or ID type instead of a string. The string form returned by
+/// `to_value` is what ends up in the `value` attribute of the underlying `<input>`
+/// and therefore in submitted form data.
+///
+/// Implemented for every `Clone + PartialEq` type that implements `ToString`, so a custom
+/// value type needs a `Display` implementation, whose output becomes `to_value`. `String`,
+/// `&'static str` and integers already have one.
+pub trait RadioValue: Clone + PartialEq + 'static {
+    /// The string form of the value used in the DOM.
+    fn to_value(&self) -> String;
+}
+
+impl<T: Clone + PartialEq + ToString + 'static> RadioValue for T {
+    fn to_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// The counter behind the group names and description IDs generated within one render.
+#[derive(Clone, Default)]
+pub(crate) struct IdCounter(Arc<AtomicUsize>);
+
+impl IdCounter {
+    /// The counter of the current thread, for components rendered outside of a scope.
+    pub(crate) fn thread() -> Self {
+        thread_local! {
+            static IDS: IdCounter = IdCounter::default();
+        }
+        IDS.with(Self::clone)
+    }
+
+    fn next(&self) -> usize {
+        self.0.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for IdCounter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A generated `name` or `id` that is only taken from the counter when it is first read.
+pub(crate) struct LazyId {
+    id: OnceLock<String>,
+    prefix: &'static str,
+    counter: IdCounter,
+}
+
+impl LazyId {
+    /// A unique `name` for the inputs of a group that was not given one.
+    pub(crate) fn group_name(counter: IdCounter) -> Self {
+        Self {
+            id: OnceLock::new(),
+            prefix: "radiors-",
+            counter,
+        }
+    }
+
+    /// An `id` for the description of a radio button, which its `aria-describedby` refers to.
+    pub(crate) fn description(counter: IdCounter) -> Self {
+        Self {
+            id: OnceLock::new(),
+            prefix: "radiors-description-",
+            counter,
+        }
+    }
+
+    /// The generated value, taking it from the counter on the first call.
+    pub(crate) fn get(&self) -> &str {
+        self.id
+            .get_or_init(|| format!("{}{}", self.prefix, self.counter.next()))
+    }
+}
+
+/// Listener for the `reset` event of the form a group's inputs belong to.
+///
+/// The listener is removed when this value is dropped.
+pub(crate) struct FormResetListener {
+    form: web_sys::HtmlFormElement,
+    callback: Closure<dyn FnMut()>,
+}
+
+impl FormResetListener {
+    /// Calls `on_reset` whenever the form of a group is reset: the form with the ID `form`,
+    /// or otherwise the one around `container`. Returns `None` when there is no such form.
+    pub(crate) fn new(
+        container: &web_sys::Element,
+        form: &str,
+        on_reset: impl FnMut() + 'static,
+    ) -> Option<Self> {
+        let form = if form.is_empty() {
+            container.closest("form").ok()??
+        } else {
+            container.owner_document()?.get_element_by_id(form)?
+        }
+        .dyn_into::<web_sys::HtmlFormElement>()
+        .ok()?;
+        let callback = Closure::<dyn FnMut()>::new(on_reset);
+        form.add_event_listener_with_callback("reset", callback.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self { form, callback })
+    }
+}
+
+impl Drop for FormResetListener {
+    fn drop(&mut self) {
+        let _ = self
+            .form
+            .remove_event_listener_with_callback("reset", self.callback.as_ref().unchecked_ref());
+    }
+}
+
+/// Listener for changes of an element's width.
+///
+/// The observer is disconnected when this value is dropped.
+pub(crate) struct WidthListener {
+    observer: web_sys::ResizeObserver,
+    callback: Closure<dyn FnMut()>,
+}
+
+impl WidthListener {
+    /// Calls `on_resize` with the width of `container` in pixels once it is observed and
+    /// whenever it changes. Returns `None` outside of a browser supporting `ResizeObserver`.
+    pub(crate) fn new(
+        container: &web_sys::Element,
+        mut on_resize: impl FnMut(u32) + 'static,
+    ) -> Option<Self> {
+        let callback = Closure::<dyn FnMut()>::new({
+            let container = container.clone();
+            move || on_resize(container.client_width().max(0) as u32)
+        });
+        let observer = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+        observer.observe(container);
+        Some(Self { observer, callback })
+    }
+}
+
+impl Drop for WidthListener {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
 
 /// Orientation
-#[derive(Clone, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default)]
 pub enum Orientation {
     Horizontal,
     #[default]
     Vertical,
+    /// A grid of equal columns, see [`Grid`].
+    Grid(Grid),
+    /// Switches between orientations at container widths.
+    ///
+    /// Each entry holds the minimum width of the group container in pixels from which its
+    /// orientation applies. The entry with the smallest width is used until the container
+    /// has been measured, e.g. during server-side rendering. Build it with
+    /// [`Orientation::responsive`].
+    Responsive(Vec<(u32, Orientation)>),
 }
 
 impl Orientation {
+    /// A grid of `columns` equal columns with the default gap, see [`Grid::new`].
+    pub fn grid(columns: u16) -> Self {
+        Orientation::Grid(Grid::new(columns))
+    }
+
+    /// An orientation switching layouts at the given container widths in pixels.
+    ///
+    /// ```rust
+    /// use radiors::Orientation;
+    ///
+    /// let orientation = Orientation::responsive([
+    ///     (0, Orientation::Vertical),
+    ///     (640, Orientation::grid(3)),
+    /// ]);
+    ///
+    /// assert_eq!(orientation.resolve(Some(320)), &Orientation::Vertical);
+    /// assert_eq!(orientation.resolve(Some(1024)), &Orientation::grid(3));
+    /// assert_eq!(orientation.resolve(None), &Orientation::Vertical);
+    /// ```
+    pub fn responsive(breakpoints: impl IntoIterator<Item = (u32, Orientation)>) -> Self {
+        let mut breakpoints = breakpoints.into_iter().collect::<Vec<_>>();
+        breakpoints.sort_by_key(|(min_width, _)| *min_width);
+        Orientation::Responsive(breakpoints)
+    }
+
+    /// Whether the orientation depends on the width of the group container.
+    pub fn is_responsive(&self) -> bool {
+        matches!(self, Orientation::Responsive(_))
+    }
+
+    /// The orientation applied to a group container of the given width in pixels, or of
+    /// unknown width. Only `Responsive` orientations depend on the width.
+    pub fn resolve(&self, width: Option<u32>) -> &Orientation {
+        match self {
+            Orientation::Responsive(breakpoints) => {
+                let applied = match width {
+                    Some(width) => breakpoints
+                        .iter()
+                        .rev()
+                        .find(|(min_width, _)| *min_width <= width)
+                        .or(breakpoints.first()),
+                    None => breakpoints.first(),
+                };
+                applied
+                    .map(|(_, orientation)| orientation.resolve(width))
+                    .unwrap_or(&Orientation::Vertical)
+            }
+            orientation => orientation,
+        }
+    }
+
+    pub fn to_style(&self) -> String {
+        match self {
+            Orientation::Horizontal => FLEX_HORIZONTAL.to_string(),
+            Orientation::Vertical => FLEX_VERTICAL.to_string(),
+            Orientation::Grid(grid) => grid.to_style(),
+            Orientation::Responsive(_) => self.resolve(None).to_style(),
+        }
+    }
+
+    /// The classes of [`STYLESHEET`] laying out a group in this orientation.
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "radiors-group radiors-group--horizontal",
+            Orientation::Vertical => "radiors-group radiors-group--vertical",
+            Orientation::Grid(_) => "radiors-group radiors-group--grid",
+            Orientation::Responsive(_) => self.resolve(None).to_class(),
+        }
+    }
+}
+
+/// Look of a radio button.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Variant {
+    /// The label, next to its optional image.
+    #[default]
+    Default,
+    /// A bordered card stacking its content, for plan and product pickers. The selected
+    /// card gets a primary colored border and, with `check_mark`, a check in its corner.
+    Card,
+    /// Connected buttons of a segmented control, for view toggles. Set on a `Group`, it
+    /// lays the options out as one row of equal segments with a sliding highlight behind
+    /// the selected one.
+    Segmented,
+}
+
+impl Variant {
+    /// The class of [`STYLESHEET`] for this variant. `Variant::Default` has none.
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            Variant::Default => "",
+            Variant::Card => "radiors-radio--card",
+            Variant::Segmented => "radiors-radio--segment",
+        }
+    }
+}
+
+/// Joins the non-empty parts with spaces, or `None` when nothing is left.
+fn join_non_empty<S: AsRef<str>>(parts: impl IntoIterator<Item = S>) -> Option<String> {
+    let joined = parts
+        .into_iter()
+        .filter_map(|part| Some(part.as_ref().trim().to_string()).filter(|part| !part.is_empty()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!joined.is_empty()).then_some(joined)
+}
+
+/// Joins inline style declarations, or `None` so that no empty `style` attribute is rendered.
+pub(crate) fn inline_style<S: AsRef<str>>(parts: impl IntoIterator<Item = S>) -> Option<String> {
+    join_non_empty(parts)
+}
+
+/// Joins CSS classes, or `None` so that no empty `class` attribute is rendered.
+pub(crate) fn class_list<S: AsRef<str>>(parts: impl IntoIterator<Item = S>) -> Option<String> {
+    join_non_empty(parts)
+}
+
+/// Built-in inline styles of a radio button's `Variant`, empty with the `stylesheet` feature.
+pub(crate) fn variant_style(variant: &Variant, selected: bool) -> String {
+    match variant {
+        Variant::Card if !STYLESHEET_MODE && selected => {
+            format!("{CARD_STYLE} {CARD_SELECTED_STYLE}")
+        }
+        Variant::Card if !STYLESHEET_MODE => CARD_STYLE.to_string(),
+        Variant::Segmented if !STYLESHEET_MODE && selected => {
+            format!("{SEGMENT_STYLE} {SEGMENT_SELECTED_STYLE}")
+        }
+        Variant::Segmented if !STYLESHEET_MODE => SEGMENT_STYLE.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// The `Variant` of a radio button inside a group: its own, unless it keeps the default
+/// look and the group sets another one.
+pub(crate) fn effective_variant(own: Variant, group: Variant) -> Variant {
+    match own {
+        Variant::Default => group,
+        own => own,
+    }
+}
+
+/// The `--radiors-segment` and `--radiors-on-segment` colors of a segmented group's
+/// highlight and selected segment, taken from its `Type`. `Type::None` keeps the primary
+/// colors of the theme.
+pub(crate) fn segment_colors(r#type: &Type) -> String {
+    let (background, foreground) = match r#type {
+        Type::None | Type::Primary => (
+            "var(--radiors-primary, #007bff)",
+            "var(--radiors-on-primary, #ffffff)",
+        ),
+        Type::Secondary => (
+            "var(--radiors-secondary, #6c757d)",
+            "var(--radiors-on-secondary, #ffffff)",
+        ),
+        Type::Success => (
+            "var(--radiors-success, #28a745)",
+            "var(--radiors-on-success, #ffffff)",
+        ),
+        Type::Info => (
+            "var(--radiors-info, #17a2b8)",
+            "var(--radiors-on-info, #ffffff)",
+        ),
+        Type::Warning => (
+            "var(--radiors-warning, #ffc107)",
+            "var(--radiors-on-warning, #212529)",
+        ),
+        Type::Danger => (
+            "var(--radiors-danger, #dc3545)",
+            "var(--radiors-on-danger, #ffffff)",
+        ),
+        Type::Custom(color) => (*color, "white"),
+    };
+    if STYLESHEET_MODE && *r#type == Type::None {
+        return String::new();
+    }
+    format!("--radiors-segment: {background}; --radiors-on-segment: {foreground};")
+}
+
+/// Inline styles of the highlight behind the selected segment of a segmented group, or
+/// `None` without a selection. Segments are equally wide, so the highlight slides to the
+/// selected one by its position among the group's options.
+pub(crate) fn segment_highlight_style<T: Clone + PartialEq>(
+    state: &RadioGroupState<T>,
+) -> Option<String> {
+    let options = state.options();
+    let selected = state.selected()?;
+    let index = options.iter().position(|option| option == selected)?;
+    let count = options.len();
+    let position = format!("left: calc(100% * {index} / {count}); width: calc(100% / {count});");
+    Some(if STYLESHEET_MODE {
+        position
+    } else {
+        format!("{SEGMENT_HIGHLIGHT_STYLE} {position}")
+    })
+}
+
+/// Cross-axis alignment of the radio buttons of a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    Stretch,
+    Baseline,
+}
+
+impl Align {
+    pub fn to_style(&self) -> &'static str {
+        match self {
+            Align::Start => "align-items: start;",
+            Align::Center => "align-items: center;",
+            Align::End => "align-items: end;",
+            Align::Stretch => "align-items: stretch;",
+            Align::Baseline => "align-items: baseline;",
+        }
+    }
+
+    /// The class of [`STYLESHEET`] for this alignment.
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            Align::Start => "radiors-align-start",
+            Align::Center => "radiors-align-center",
+            Align::End => "radiors-align-end",
+            Align::Stretch => "radiors-align-stretch",
+            Align::Baseline => "radiors-align-baseline",
+        }
+    }
+}
+
+/// Main-axis distribution of the radio buttons of a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Justify {
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+impl Justify {
     pub fn to_style(&self) -> &'static str {
         match self {
-            Orientation::Horizontal => FLEX_HORIZONTAL,
-            Orientation::Vertical => FLEX_VERTICAL,
+            Justify::Start => "justify-content: start;",
+            Justify::Center => "justify-content: center;",
+            Justify::End => "justify-content: end;",
+            Justify::SpaceBetween => "justify-content: space-between;",
+            Justify::SpaceAround => "justify-content: space-around;",
+            Justify::SpaceEvenly => "justify-content: space-evenly;",
         }
     }
+
+    /// The class of [`STYLESHEET`] for this distribution.
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            Justify::Start => "radiors-justify-start",
+            Justify::Center => "radiors-justify-center",
+            Justify::End => "radiors-justify-end",
+            Justify::SpaceBetween => "radiors-justify-between",
+            Justify::SpaceAround => "radiors-justify-around",
+            Justify::SpaceEvenly => "radiors-justify-evenly",
+        }
+    }
+}
+
+/// Grid layout of a group, used by `Orientation::Grid`.
+///
+/// Options fill `columns` equal columns, row by row. With a `min_column_width`, columns
+/// never shrink below that width: the grid wraps into fewer columns on narrow containers,
+/// down to a single one, without any breakpoint.
+///
+/// # Examples
+/// ```rust
+/// use radiors::{Grid, Orientation};
+///
+/// let plans = Orientation::Grid(Grid::new(3).with_gap("24px").with_min_column_width("180px"));
+/// assert!(plans.to_style().starts_with("display: grid;"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    /// Maximum number of columns.
+    pub columns: u16,
+    /// Space between rows and columns.
+    pub gap: String,
+    /// Narrowest width of a column before the grid wraps. Empty to never wrap.
+    pub min_column_width: String,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl Grid {
+    /// A grid of `columns` equal columns with a `16px` gap that never wraps.
+    pub fn new(columns: u16) -> Self {
+        Self {
+            columns: columns.max(1),
+            gap: "16px".into(),
+            min_column_width: String::new(),
+        }
+    }
+
+    /// Sets the space between rows and columns.
+    pub fn with_gap(mut self, gap: impl Into<String>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+
+    /// Sets the narrowest width of a column before the grid wraps into fewer columns.
+    pub fn with_min_column_width(mut self, min_column_width: impl Into<String>) -> Self {
+        self.min_column_width = min_column_width.into();
+        self
+    }
+
+    /// The inline styles of the grid.
+    pub fn to_style(&self) -> String {
+        format!("display: grid; {}", self.template())
+    }
+
+    /// The column template and gap, which cannot be expressed by the classes of [`STYLESHEET`].
+    fn template(&self) -> String {
+        let columns = self.columns.max(1);
+        let template = if self.min_column_width.is_empty() {
+            format!("repeat({columns}, minmax(0, 1fr))")
+        } else {
+            format!(
+                "repeat(auto-fill, minmax(max({}, calc((100% - {} * {}) / {columns})), 1fr))",
+                self.min_column_width,
+                columns - 1,
+                self.gap
+            )
+        };
+        format!("grid-template-columns: {template}; gap: {};", self.gap)
+    }
 }
 
 /// Radio Button Size
-#[derive(Clone, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default)]
 pub enum Size {
     #[default]
     XSmall,
@@ -37,21 +609,63 @@ pub enum Size {
 impl Size {
     pub fn to_style(&self) -> String {
         match self {
-            Size::XSmall => "padding: 5px;".to_string(),
-            Size::Small => "padding: 7px;".to_string(),
-            Size::Medium => "padding: 10px;".to_string(),
-            Size::Large => "padding: 20px;".to_string(),
-            Size::XLarge => "padding: 25px;".to_string(),
-            Size::XXLarge => "padding: 30px;".to_string(),
+            Size::XSmall => "padding: var(--radiors-spacing-xsmall, 5px);".to_string(),
+            Size::Small => "padding: var(--radiors-spacing-small, 7px);".to_string(),
+            Size::Medium => "padding: var(--radiors-spacing-medium, 10px);".to_string(),
+            Size::Large => "padding: var(--radiors-spacing-large, 20px);".to_string(),
+            Size::XLarge => "padding: var(--radiors-spacing-xlarge, 25px);".to_string(),
+            Size::XXLarge => "padding: var(--radiors-spacing-xxlarge, 30px);".to_string(),
             Size::Custom(custom_size) => {
                 format!("padding: {};  width: {};", custom_size, custom_size)
             }
         }
     }
+
+    /// The `gap` between the radio buttons of a group spaced by this size, which follows
+    /// the theme's spacing scale.
+    pub fn to_gap(&self) -> String {
+        match self {
+            Size::XSmall => "gap: var(--radiors-spacing-xsmall, 5px);".to_string(),
+            Size::Small => "gap: var(--radiors-spacing-small, 7px);".to_string(),
+            Size::Medium => "gap: var(--radiors-spacing-medium, 10px);".to_string(),
+            Size::Large => "gap: var(--radiors-spacing-large, 20px);".to_string(),
+            Size::XLarge => "gap: var(--radiors-spacing-xlarge, 25px);".to_string(),
+            Size::XXLarge => "gap: var(--radiors-spacing-xxlarge, 30px);".to_string(),
+            Size::Custom(custom_size) => format!("gap: {};", custom_size),
+        }
+    }
+
+    /// The class of [`STYLESHEET`] for a group gap of this size. `Size::Custom` has none and
+    /// keeps its inline style.
+    pub fn to_gap_class(&self) -> &'static str {
+        match self {
+            Size::XSmall => "radiors-gap-xs",
+            Size::Small => "radiors-gap-sm",
+            Size::Medium => "radiors-gap-md",
+            Size::Large => "radiors-gap-lg",
+            Size::XLarge => "radiors-gap-xl",
+            Size::XXLarge => "radiors-gap-xxl",
+            Size::Custom(_) => "",
+        }
+    }
+
+    /// The class of [`STYLESHEET`] for this size. `Size::Custom` has none and keeps its
+    /// inline style.
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            Size::XSmall => "radiors-size-xs",
+            Size::Small => "radiors-size-sm",
+            Size::Medium => "radiors-size-md",
+            Size::Large => "radiors-size-lg",
+            Size::XLarge => "radiors-size-xl",
+            Size::XXLarge => "radiors-size-xxl",
+            Size::Custom(_) => "",
+        }
+    }
 }
 
 /// Styling types
-#[derive(Clone, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default)]
 pub enum Type {
     Primary,
     Secondary,
@@ -67,16 +681,870 @@ pub enum Type {
 impl Type {
     pub fn to_style(&self) -> String {
         match self {
-            Type::Primary => "background-color: #007bff; color: white;".to_string(),
-            Type::Secondary => "background-color: #6c757d; color: white;".to_string(),
-            Type::Success => "background-color: #28a745; color: white;".to_string(),
-            Type::Info => "background-color: #17a2b8; color: white;".to_string(),
-            Type::Warning => "background-color: #ffc107; color: white;".to_string(),
-            Type::Danger => "background-color: #dc3545; color: white;".to_string(),
+            Type::Primary => "background-color: var(--radiors-primary, #007bff); color: var(--radiors-on-primary, #ffffff);".to_string(),
+            Type::Secondary => "background-color: var(--radiors-secondary, #6c757d); color: var(--radiors-on-secondary, #ffffff);".to_string(),
+            Type::Success => "background-color: var(--radiors-success, #28a745); color: var(--radiors-on-success, #ffffff);".to_string(),
+            Type::Info => "background-color: var(--radiors-info, #17a2b8); color: var(--radiors-on-info, #ffffff);".to_string(),
+            Type::Warning => "background-color: var(--radiors-warning, #ffc107); color: var(--radiors-on-warning, #212529);".to_string(),
+            Type::Danger => "background-color: var(--radiors-danger, #dc3545); color: var(--radiors-on-danger, #ffffff);".to_string(),
             Type::None => "".to_string(),
             Type::Custom(custom_color) => {
                 format!("background-color: {}; color: white;", custom_color).to_string()
             }
         }
     }
+
+    /// The class of [`STYLESHEET`] for this type. `Type::None` has none, and
+    /// `Type::Custom` keeps its inline style.
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            Type::Primary => "radiors-type-primary",
+            Type::Secondary => "radiors-type-secondary",
+            Type::Success => "radiors-type-success",
+            Type::Info => "radiors-type-info",
+            Type::Warning => "radiors-type-warning",
+            Type::Danger => "radiors-type-danger",
+            Type::None | Type::Custom(_) => "",
+        }
+    }
+}
+
+/// Layout of a group container: its resolved `Orientation` together with the gap,
+/// alignment, justification and wrapping applied on top of it.
+pub(crate) struct GroupLayout<'a> {
+    pub(crate) variant: Variant,
+    pub(crate) r#type: &'a Type,
+    pub(crate) orientation: &'a Orientation,
+    pub(crate) gap: Option<&'a Size>,
+    pub(crate) align: Option<Align>,
+    pub(crate) justify: Option<Justify>,
+    pub(crate) wrap: bool,
+}
+
+impl GroupLayout<'_> {
+    /// Built-in classes, empty unless the `stylesheet` feature is enabled.
+    pub(crate) fn to_class(&self) -> String {
+        if !STYLESHEET_MODE {
+            return String::new();
+        }
+        if self.variant == Variant::Segmented {
+            return "radiors-group radiors-group--segmented".to_string();
+        }
+        [
+            Some(self.orientation.to_class()),
+            self.gap.map(Size::to_gap_class),
+            self.align.as_ref().map(Align::to_class),
+            self.justify.as_ref().map(Justify::to_class),
+            self.wrap.then_some("radiors-wrap"),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|class| !class.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Built-in inline styles. With the `stylesheet` feature only the column template of a
+    /// `Grid` and a `Size::Custom` gap are styled inline.
+    pub(crate) fn to_style(&self) -> String {
+        if self.variant == Variant::Segmented {
+            let colors = segment_colors(self.r#type);
+            return if STYLESHEET_MODE {
+                colors
+            } else {
+                format!("{SEGMENTED_GROUP_STYLE} {colors}")
+            };
+        }
+        let mut parts = Vec::new();
+        if STYLESHEET_MODE {
+            if let Orientation::Grid(grid) = self.orientation {
+                parts.push(grid.template());
+            }
+            if let Some(gap @ Size::Custom(_)) = self.gap {
+                parts.push(gap.to_gap());
+            }
+        } else {
+            parts.push(self.orientation.to_style());
+            parts.extend(self.gap.map(Size::to_gap));
+            parts.extend(self.align.map(|align| align.to_style().to_string()));
+            parts.extend(self.justify.map(|justify| justify.to_style().to_string()));
+            if self.wrap {
+                parts.push("flex-wrap: wrap;".to_string());
+            }
+        }
+        parts.join(" ")
+    }
+}
+
+/// Built-in classes of a radio button, empty unless the `stylesheet` feature is enabled.
+pub(crate) fn radio_class(
+    variant: &Variant,
+    r#type: &Type,
+    size: &Size,
+    selected: bool,
+    disabled: bool,
+) -> String {
+    if !STYLESHEET_MODE {
+        return String::new();
+    }
+    let mut class = String::from("radiors-radio");
+    for modifier in [
+        Some(variant.to_class()),
+        selected.then_some("radiors-radio--selected"),
+        disabled.then_some("radiors-radio--disabled"),
+        Some(r#type.to_class()),
+        Some(size.to_class()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|modifier| !modifier.is_empty())
+    {
+        class.push(' ');
+        class.push_str(modifier);
+    }
+    class
+}
+
+/// Built-in inline styles of a radio button's `Type`. With the `stylesheet` feature only
+/// `Type::Custom` is styled inline.
+pub(crate) fn type_style(r#type: &Type) -> String {
+    match r#type {
+        Type::Custom(_) => r#type.to_style(),
+        _ if STYLESHEET_MODE => String::new(),
+        _ => r#type.to_style(),
+    }
+}
+
+/// Built-in inline styles of a radio button's `Size`. With the `stylesheet` feature only
+/// `Size::Custom` is styled inline.
+pub(crate) fn size_style(size: &Size) -> String {
+    match size {
+        Size::Custom(_) => size.to_style(),
+        _ if STYLESHEET_MODE => String::new(),
+        _ => size.to_style(),
+    }
+}
+
+/// Inline styles tying a radio button to the `--radiors-surface`, `--radiors-radius`,
+/// `--radiors-selected-indicator` and `--radiors-focus-ring` variables of the current [`Theme`].
+///
+/// Empty with the `stylesheet` feature, where the stylesheet applies them through classes
+/// and `:focus-visible`.
+pub(crate) fn themed_radio_style(selected: bool, focused: bool) -> String {
+    if STYLESHEET_MODE {
+        return String::new();
+    }
+    let indicator = if selected {
+        "var(--radiors-selected-indicator, 0 0 transparent)"
+    } else {
+        "0 0 transparent"
+    };
+    let ring = if focused {
+        "var(--radiors-focus-ring, 0 0 transparent)"
+    } else {
+        "0 0 transparent"
+    };
+    format!(
+        "background-color: var(--radiors-surface, transparent); color: var(--radiors-on-surface, inherit); \
+         border-radius: var(--radiors-radius, 0); box-shadow: {indicator}, {ring};"
+    )
+}
+
+/// Background and text color of one [`Type`], or of the radio buttons themselves.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Swatch {
+    /// Background color of radio buttons of this type.
+    pub background: String,
+    /// Text color drawn on top of `background`.
+    pub foreground: String,
+}
+
+impl Swatch {
+    /// Creates a swatch from a background and a text color.
+    pub fn new(background: impl Into<String>, foreground: impl Into<String>) -> Self {
+        Self {
+            background: background.into(),
+            foreground: foreground.into(),
+        }
+    }
+}
+
+/// Padding of radio buttons for every [`Size`] except `Size::Custom`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spacing {
+    /// Padding of `Size::XSmall`.
+    pub xsmall: String,
+    /// Padding of `Size::Small`.
+    pub small: String,
+    /// Padding of `Size::Medium`.
+    pub medium: String,
+    /// Padding of `Size::Large`.
+    pub large: String,
+    /// Padding of `Size::XLarge`.
+    pub xlarge: String,
+    /// Padding of `Size::XXLarge`.
+    pub xxlarge: String,
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            xsmall: "5px".into(),
+            small: "7px".into(),
+            medium: "10px".into(),
+            large: "20px".into(),
+            xlarge: "25px".into(),
+            xxlarge: "30px".into(),
+        }
+    }
+}
+
+/// Design tokens used by the built-in styles of `Group` and `Radio`.
+///
+/// A theme is provided to a subtree through the `ThemeProvider` component of each
+/// adapter. Every `Group` below it emits the theme as `--radiors-*` CSS custom
+/// properties on its container, which the `Type`, `Size`, selected indicator and focus
+/// ring styles of its radio buttons read. Providing a different theme restyles every
+/// group at runtime without touching the individual `Radio` components.
+///
+/// The variables can also be set from a stylesheet or on any ancestor element with
+/// [`Theme::to_style`]. Variables that are not set fall back to the values of
+/// `Theme::default()`, which matches the built-in look.
+///
+/// `focus_ring` and `selected_indicator` are `box-shadow` values, so use
+/// `0 0 transparent` rather than `none` to turn them off.
+///
+/// # Examples
+/// ```rust
+/// use radiors::{Swatch, Theme};
+///
+/// let theme = Theme {
+///     primary: Swatch::new("#6d28d9", "#ffffff"),
+///     radius: "8px".into(),
+///     focus_ring: "0 0 0 3px rgba(109, 40, 217, 0.4)".into(),
+///     ..Theme::default()
+/// };
+///
+/// assert!(theme.to_style().contains("--radiors-primary: #6d28d9;"));
+/// assert!(theme.to_style().contains("--radiors-radius: 8px;"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// Colors of `Type::Primary`.
+    pub primary: Swatch,
+    /// Colors of `Type::Secondary`.
+    pub secondary: Swatch,
+    /// Colors of `Type::Success`.
+    pub success: Swatch,
+    /// Colors of `Type::Info`.
+    pub info: Swatch,
+    /// Colors of `Type::Warning`.
+    pub warning: Swatch,
+    /// Colors of `Type::Danger`.
+    pub danger: Swatch,
+    /// Colors of radio buttons without a `Type`, which custom styles can override.
+    pub surface: Swatch,
+    /// Corner radius of radio buttons.
+    pub radius: String,
+    /// Padding of radio buttons per `Size`.
+    pub spacing: Spacing,
+    /// `box-shadow` drawn around the focused radio button.
+    pub focus_ring: String,
+    /// `box-shadow` drawn around the selected radio button.
+    pub selected_indicator: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: Swatch::new("#007bff", "#ffffff"),
+            secondary: Swatch::new("#6c757d", "#ffffff"),
+            success: Swatch::new("#28a745", "#ffffff"),
+            info: Swatch::new("#17a2b8", "#ffffff"),
+            warning: Swatch::new("#ffc107", "#212529"),
+            danger: Swatch::new("#dc3545", "#ffffff"),
+            surface: Swatch::new("transparent", "inherit"),
+            radius: "0".into(),
+            spacing: Spacing::default(),
+            focus_ring: "0 0 transparent".into(),
+            selected_indicator: "0 0 transparent".into(),
+        }
+    }
+}
+
+impl Theme {
+    /// The light theme, which is also `Theme::default()`.
+    pub fn light() -> Self {
+        Self::default()
+    }
+
+    /// A dark theme with lighter `Type` colors and a dark surface, so radio buttons keep
+    /// their contrast on dark backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            primary: Swatch::new("#4dabf7", "#0b1220"),
+            secondary: Swatch::new("#adb5bd", "#0b1220"),
+            success: Swatch::new("#51cf66", "#0b1220"),
+            info: Swatch::new("#3bc9db", "#0b1220"),
+            warning: Swatch::new("#ffd43b", "#0b1220"),
+            danger: Swatch::new("#ff6b6b", "#0b1220"),
+            surface: Swatch::new("#1f2937", "#f9fafb"),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the theme as `--radiors-*` custom property declarations, ready to be used
+    /// as an inline `style` or inside a CSS rule.
+    pub fn to_style(&self) -> String {
+        self.declarations()
+            .iter()
+            .map(|(name, value)| format!("--radiors-{name}: {value};"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns a CSS rule setting the theme's `--radiors-*` custom properties on `selector`,
+    /// e.g. to generate themes for [`STYLESHEET`] at build time without inline styles.
+    ///
+    /// ```rust
+    /// use radiors::Theme;
+    ///
+    /// let css = Theme::dark().to_css(".dashboard");
+    /// assert!(css.starts_with(".dashboard {\n  --radiors-primary: #4dabf7;\n"));
+    /// assert!(css.ends_with("}\n"));
+    /// ```
+    pub fn to_css(&self, selector: &str) -> String {
+        let mut css = format!("{selector} {{\n");
+        for (name, value) in self.declarations() {
+            css.push_str(&format!("  --radiors-{name}: {value};\n"));
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// The custom property names, without the `--radiors-` prefix, and their values.
+    fn declarations(&self) -> Vec<(String, &str)> {
+        let mut declarations = Vec::new();
+        for (name, swatch) in [
+            ("primary", &self.primary),
+            ("secondary", &self.secondary),
+            ("success", &self.success),
+            ("info", &self.info),
+            ("warning", &self.warning),
+            ("danger", &self.danger),
+            ("surface", &self.surface),
+        ] {
+            declarations.push((name.to_string(), swatch.background.as_str()));
+            declarations.push((format!("on-{name}"), swatch.foreground.as_str()));
+        }
+        for (name, padding) in [
+            ("xsmall", &self.spacing.xsmall),
+            ("small", &self.spacing.small),
+            ("medium", &self.spacing.medium),
+            ("large", &self.spacing.large),
+            ("xlarge", &self.spacing.xlarge),
+            ("xxlarge", &self.spacing.xxlarge),
+        ] {
+            declarations.push((format!("spacing-{name}"), padding.as_str()));
+        }
+        declarations.push(("radius".to_string(), &self.radius));
+        declarations.push(("focus-ring".to_string(), &self.focus_ring));
+        declarations.push(("selected-indicator".to_string(), &self.selected_indicator));
+        declarations
+    }
+}
+
+/// Which of the light and dark themes of a `ThemeProvider` is applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    /// Always use the light theme.
+    Light,
+    /// Always use the dark theme.
+    Dark,
+    /// Follow the `prefers-color-scheme` media query of the browser.
+    #[default]
+    System,
+}
+
+impl ColorScheme {
+    /// Returns whether the dark theme applies, given whether the browser prefers a dark
+    /// color scheme.
+    pub fn is_dark(self, prefers_dark: bool) -> bool {
+        match self {
+            ColorScheme::Light => false,
+            ColorScheme::Dark => true,
+            ColorScheme::System => prefers_dark,
+        }
+    }
+}
+
+/// Listener for changes of the `prefers-color-scheme` media query.
+///
+/// The listener is removed when this value is dropped.
+pub(crate) struct ColorSchemeListener {
+    query: web_sys::MediaQueryList,
+    callback: Closure<dyn FnMut()>,
+}
+
+impl ColorSchemeListener {
+    /// Calls `on_change` with whether the browser prefers a dark color scheme, once right
+    /// away and again whenever the preference changes. Returns `None` outside of a browser.
+    pub(crate) fn new(mut on_change: impl FnMut(bool) + 'static) -> Option<Self> {
+        let query = web_sys::window()?
+            .match_media("(prefers-color-scheme: dark)")
+            .ok()??;
+        on_change(query.matches());
+        let callback = Closure::<dyn FnMut()>::new({
+            let query = query.clone();
+            move || on_change(query.matches())
+        });
+        query
+            .add_event_listener_with_callback("change", callback.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self { query, callback })
+    }
+}
+
+impl Drop for ColorSchemeListener {
+    fn drop(&mut self) {
+        let _ = self
+            .query
+            .remove_event_listener_with_callback("change", self.callback.as_ref().unchecked_ref());
+    }
+}
+
+/// Descriptor of a single option of a data-driven radio group.
+///
+/// Passed to the `options` property of a `Group` in every adapter, which renders one
+/// `Radio` per descriptor, so groups can be built from records loaded at runtime.
+///
+/// # Examples
+/// ```rust
+/// use radiors::RadioOption;
+///
+/// let options = vec![
+///     RadioOption::new(1, "Standard"),
+///     RadioOption::new(2, "Express").with_description("Next business day"),
+///     RadioOption::new(3, "Drone").with_src("drone.png").with_disabled(true),
+/// ];
+///
+/// assert_eq!(options[1].label, "Express");
+/// assert!(options[2].disabled);
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RadioOption<T> {
+    /// The value of the option.
+    pub value: T,
+    /// Text displayed next to the radio button.
+    pub label: String,
+    /// Optional image URL displayed alongside the label. Empty for no image.
+    pub src: String,
+    /// Whether the option is disabled.
+    pub disabled: bool,
+    /// Secondary text displayed under the label. Empty for no description.
+    pub description: String,
+}
+
+impl<T> RadioOption<T> {
+    /// Creates an enabled option with the given value and label.
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            src: String::new(),
+            disabled: false,
+            description: String::new(),
+        }
+    }
+
+    /// Sets the image URL displayed alongside the label.
+    pub fn with_src(mut self, src: impl Into<String>) -> Self {
+        self.src = src.into();
+        self
+    }
+
+    /// Sets whether the option is disabled.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the secondary text displayed under the label.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
+
+/// A value type whose values form a fixed set of radio options.
+///
+/// Pass `options()` straight to the `options` property of a `Group` to render one `Radio`
+/// per value. With the `derive` feature, `#[derive(RadioOptions)]` implements this trait
+/// and `Display` for an enum of unit variants, unless `#[radio(no_display)]` opts out of
+/// the latter. Variant attributes set the value, label, image and disabled flag of each
+/// option:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use radiors::RadioOptions;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, RadioOptions)]
+/// enum Plan {
+///     #[radio(value = "basic", label = "Basic")]
+///     Basic,
+///     #[radio(value = "pro", label = "Pro", description = "For teams")]
+///     Pro,
+///     #[radio(value = "enterprise", label = "Enterprise", disabled)]
+///     Enterprise,
+/// }
+///
+/// assert_eq!(Plan::Pro.as_value(), "pro");
+/// assert_eq!(Plan::from_value("pro"), Some(Plan::Pro));
+/// assert_eq!(Plan::options()[2].label, "Enterprise");
+/// assert!(Plan::options()[2].disabled);
+/// # }
+/// ```
+pub trait RadioOptions: RadioValue {
+    /// All options, in declaration order.
+    fn options() -> Vec<RadioOption<Self>>;
+
+    /// The string form of the value, used as the `value` of the underlying `<input>`.
+    fn as_value(&self) -> &'static str;
+
+    /// Parses a value from its string form, the inverse of `as_value`.
+    fn from_value(value: &str) -> Option<Self>;
+}
+
+/// Framework-agnostic selection state of a radio group.
+///
+/// `RadioGroupState` tracks the registered options in render order, which of them are
+/// disabled, the selected and focused values and whether a selection is required. The
+/// Yew, Dioxus and Leptos adapters all drive their `Group` through the transitions
+/// defined here, so selection rules behave the same in every framework.
+///
+/// Transitions that can change the selection return `true` when it actually changed,
+/// which is when adapters emit their `onchange` callback.
+///
+/// # Examples
+/// ```rust
+/// use radiors::common::RadioGroupState;
+///
+/// let mut state = RadioGroupState::new(["mac", "windows", "linux"])
+///     .with_selected("mac")
+///     .with_disabled("windows");
+///
+/// assert!(state.select_next());
+/// assert_eq!(state.selected(), Some(&"linux"));
+///
+/// state.reset();
+/// assert_eq!(state.selected(), Some(&"mac"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadioGroupState<T> {
+    options: Vec<T>,
+    disabled: Vec<T>,
+    selected: Option<T>,
+    initial: Option<T>,
+    focused: Option<T>,
+    required: bool,
+}
+
+impl<T> Default for RadioGroupState<T> {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            disabled: Vec::new(),
+            selected: None,
+            initial: None,
+            focused: None,
+            required: false,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> RadioGroupState<T> {
+    /// Creates a state holding the given options in order, with nothing selected.
+    pub fn new(options: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            options: options.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the initial selection, which is also the value restored by `reset`.
+    pub fn with_selected(mut self, value: T) -> Self {
+        self.initial = Some(value.clone());
+        self.selected = Some(value);
+        self
+    }
+
+    /// Marks an option as disabled.
+    pub fn with_disabled(mut self, value: T) -> Self {
+        self.set_disabled(value, true);
+        self
+    }
+
+    /// Sets whether a selection is required for the group to be valid.
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Appends an option, or updates its disabled flag if it is already registered.
+    pub fn register(&mut self, value: T, disabled: bool) {
+        if !self.options.contains(&value) {
+            self.options.push(value.clone());
+        }
+        self.set_disabled(value, disabled);
+    }
+
+    /// Removes an option, dropping it from the disabled set and the focus.
+    ///
+    /// The selection is kept, so a controlled value is not lost while options re-render.
+    pub fn unregister(&mut self, value: &T) {
+        self.options.retain(|option| option != value);
+        self.disabled.retain(|option| option != value);
+        if self.focused.as_ref() == Some(value) {
+            self.focused = None;
+        }
+    }
+
+    /// Enables or disables an option.
+    pub fn set_disabled(&mut self, value: T, disabled: bool) {
+        let position = self.disabled.iter().position(|option| *option == value);
+        match (position, disabled) {
+            (None, true) => self.disabled.push(value),
+            (Some(index), false) => {
+                self.disabled.remove(index);
+            }
+            _ => {}
+        }
+    }
+
+    /// Sets whether a selection is required.
+    pub fn set_required(&mut self, required: bool) {
+        self.required = required;
+    }
+
+    /// Overwrites the selection without any checks.
+    ///
+    /// Used by adapters to sync the state with a value owned by the application.
+    pub fn set_selected(&mut self, value: Option<T>) {
+        self.selected = value;
+    }
+
+    /// The registered options in order.
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    /// The selected value, if any.
+    pub fn selected(&self) -> Option<&T> {
+        self.selected.as_ref()
+    }
+
+    /// The focused value, if any.
+    pub fn focused(&self) -> Option<&T> {
+        self.focused.as_ref()
+    }
+
+    /// Whether `value` is the selected value.
+    pub fn is_selected(&self, value: &T) -> bool {
+        self.selected.as_ref() == Some(value)
+    }
+
+    /// Whether `value` is the focused value.
+    pub fn is_focused(&self, value: &T) -> bool {
+        self.focused.as_ref() == Some(value)
+    }
+
+    /// Whether `value` is disabled.
+    pub fn is_disabled(&self, value: &T) -> bool {
+        self.disabled.contains(value)
+    }
+
+    /// Whether a selection is required.
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Whether the state satisfies the `required` constraint.
+    pub fn is_valid(&self) -> bool {
+        !self.required || self.selected.is_some()
+    }
+
+    /// Selects `value` if it is a registered, enabled option.
+    ///
+    /// Also moves the focus to `value`. Returns `true` when the selection changed.
+    pub fn select(&mut self, value: &T) -> bool {
+        if !self.options.contains(value) || self.is_disabled(value) {
+            return false;
+        }
+        self.focused = Some(value.clone());
+        if self.is_selected(value) {
+            return false;
+        }
+        self.selected = Some(value.clone());
+        true
+    }
+
+    /// Moves the focus to `value` if it is a registered, enabled option.
+    pub fn focus(&mut self, value: &T) -> bool {
+        if !self.options.contains(value) || self.is_disabled(value) {
+            return false;
+        }
+        self.focused = Some(value.clone());
+        true
+    }
+
+    /// Moves the focus to the next enabled option, wrapping at the end.
+    pub fn focus_next(&mut self) -> bool {
+        self.step(1)
+    }
+
+    /// Moves the focus to the previous enabled option, wrapping at the start.
+    pub fn focus_prev(&mut self) -> bool {
+        self.step(-1)
+    }
+
+    /// Moves the focus to the first enabled option.
+    pub fn focus_first(&mut self) -> bool {
+        let value = self.enabled().next().cloned();
+        match value {
+            Some(value) => self.focus(&value),
+            None => false,
+        }
+    }
+
+    /// Moves the focus to the last enabled option.
+    pub fn focus_last(&mut self) -> bool {
+        let value = self.enabled().last().cloned();
+        match value {
+            Some(value) => self.focus(&value),
+            None => false,
+        }
+    }
+
+    /// Focuses and selects the next enabled option. Returns `true` when the selection changed.
+    pub fn select_next(&mut self) -> bool {
+        self.focus_next() && self.select_focused()
+    }
+
+    /// Focuses and selects the previous enabled option. Returns `true` when the selection changed.
+    pub fn select_prev(&mut self) -> bool {
+        self.focus_prev() && self.select_focused()
+    }
+
+    /// Focuses and selects the first enabled option. Returns `true` when the selection changed.
+    pub fn select_first(&mut self) -> bool {
+        self.focus_first() && self.select_focused()
+    }
+
+    /// Focuses and selects the last enabled option. Returns `true` when the selection changed.
+    pub fn select_last(&mut self) -> bool {
+        self.focus_last() && self.select_focused()
+    }
+
+    /// Selects the focused option. Returns `true` when the selection changed.
+    pub fn select_focused(&mut self) -> bool {
+        match self.focused.clone() {
+            Some(value) => self.select(&value),
+            None => false,
+        }
+    }
+
+    /// The option that takes part in the tab sequence (roving tabindex).
+    ///
+    /// This is the selected option when it is enabled, otherwise the focused option,
+    /// otherwise the first enabled option. Every other option should get `tabindex="-1"`.
+    pub fn tab_stop(&self) -> Option<&T> {
+        let usable = |value: &&T| self.options.contains(value) && !self.is_disabled(value);
+        self.selected
+            .as_ref()
+            .filter(usable)
+            .or(self.focused.as_ref().filter(usable))
+            .or_else(|| self.enabled().next())
+    }
+
+    /// Whether `value` is the option that takes part in the tab sequence.
+    pub fn is_tab_stop(&self, value: &T) -> bool {
+        self.tab_stop() == Some(value)
+    }
+
+    /// Like [`is_tab_stop`](Self::is_tab_stop), but leaves the tab stop to a selected option
+    /// that has not been registered yet, for radio buttons that register while they render.
+    pub fn is_tab_stop_while_registering(&self, value: &T) -> bool {
+        let pending = self
+            .selected
+            .as_ref()
+            .is_some_and(|selected| selected != value && !self.options.contains(selected));
+        !pending && self.is_tab_stop(value)
+    }
+
+    /// Applies the WAI-ARIA radio group keyboard interaction for a `KeyboardEvent.key` value.
+    ///
+    /// Arrow keys select the next or previous enabled option, wrapping at the ends, `Home`
+    /// and `End` select the first and last enabled option and `Space` selects the focused
+    /// one. Returns `None` for keys the group does not handle, otherwise whether the
+    /// selection changed. Adapters should prevent the default action of handled keys.
+    pub fn handle_key(&mut self, key: &str) -> Option<bool> {
+        let changed = match key {
+            "ArrowDown" | "ArrowRight" => self.select_next(),
+            "ArrowUp" | "ArrowLeft" => self.select_prev(),
+            "Home" => self.select_first(),
+            "End" => self.select_last(),
+            " " | "Spacebar" => {
+                if self.focused.is_none() {
+                    self.focused = self.tab_stop().cloned();
+                }
+                self.select_focused()
+            }
+            _ => return None,
+        };
+        Some(changed)
+    }
+
+    /// Clears the selection. Returns `true` when something was selected.
+    pub fn clear(&mut self) -> bool {
+        self.selected.take().is_some()
+    }
+
+    /// Restores the initial selection. Returns `true` when the selection changed.
+    pub fn reset(&mut self) -> bool {
+        let changed = self.selected != self.initial;
+        self.selected = self.initial.clone();
+        changed
+    }
+
+    fn enabled(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.options
+            .iter()
+            .filter(|option| !self.disabled.contains(option))
+    }
+
+    fn step(&mut self, offset: isize) -> bool {
+        let len = self.options.len() as isize;
+        let current = self
+            .focused
+            .as_ref()
+            .or(self.selected.as_ref())
+            .and_then(|value| self.options.iter().position(|option| option == value));
+        let Some(current) = current else {
+            return if offset > 0 {
+                self.focus_first()
+            } else {
+                self.focus_last()
+            };
+        };
+        for distance in 1..=len {
+            let index = (current as isize + offset * distance).rem_euclid(len) as usize;
+            let value = &self.options[index];
+            if !self.is_disabled(value) {
+                self.focused = Some(value.clone());
+                return true;
+            }
+        }
+        false
+    }
 }
@@ -1,6 +1,26 @@
-use crate::common::{Orientation, Size, Type, HIDDEN_INPUT_STYLE};
+use crate::common::{
+    class_list, effective_variant, inline_style, radio_class, segment_highlight_style, size_style,
+    themed_radio_style, type_style, variant_style, Align, ColorScheme, ColorSchemeListener,
+    FormResetListener, GroupLayout, IdCounter, Justify, LazyId, Orientation, RadioGroupState,
+    RadioOption, RadioValue, Size, Theme, Type, Variant, WidthListener, CHECK_MARK_CLASS,
+    CHECK_MARK_STYLE, DESCRIPTION_CLASS, DESCRIPTION_STYLE, HIDDEN_INPUT_CLASS, HIDDEN_INPUT_STYLE,
+    SEGMENT_HIGHLIGHT_CLASS,
+};
 use dioxus::prelude::*;
-use dioxus_core::AttributeValue;
+use dioxus::signals::warnings::signal_write_in_component_body;
+use dioxus::warnings::Warning;
+
+/// Maps an empty string prop to `None` so that the attribute is omitted.
+fn non_empty(value: &str) -> Option<&str> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// The counter of the generated names and IDs, kept at the root of the current `VirtualDom`.
+fn root_ids() -> IdCounter {
+    ScopeId::ROOT
+        .consume_context()
+        .unwrap_or_else(|| ScopeId::ROOT.provide_context(IdCounter::default()))
+}
 
 /// Properties for configuring the `Group` component.
 ///
@@ -8,92 +28,278 @@ use dioxus_core::AttributeValue;
 /// styles, orientation, and behavior. This is useful for creating user-selectable options
 /// presented as a single group.
 ///
-/// It supports horizontal or vertical layouts, CSS customizations, and child components of type `Radio` only (TODO).
+/// It supports horizontal or vertical layouts, CSS customizations, and any children. `Radio` components anywhere inside
+/// the group pick up its selection state through context.
+///
+/// The group is generic over the value type `T` of its `Radio` children.
 #[derive(Props, PartialEq, Clone)]
-pub struct GroupProps {
-    /// The selected value of the radio group.
+pub struct GroupProps<T: RadioValue> {
+    /// The selected value of a controlled group.
     ///
-    /// This represents the current value selected in the group. It can be bound to a state
-    /// to reflect changes dynamically. Defaults to an empty string if not provided.
+    /// See the [`common`](crate::common) module for when a group is controlled. Defaults to `None`.
     #[props(default)]
-    pub selected: String,
+    pub selected: ReadOnlySignal<Option<T>>,
+
+    /// The initially selected value of an uncontrolled group.
+    ///
+    /// Later changes are ignored. Defaults to `None`.
+    #[props(default)]
+    pub default_selected: Option<T>,
+
+    /// A signal bound to the selection of the radio group.
+    ///
+    /// Takes precedence over `selected` and `default_selected`. Defaults to `None`.
+    #[props(default)]
+    pub bind: Option<Signal<T>>,
 
     /// Callback for when the selected value changes.
     ///
     /// This callback is triggered whenever the user selects a different radio button. It
-    /// provides the new selected value as a string. Defaults to a no-op.
+    /// provides the new selected value. Defaults to a no-op.
     #[props(default)]
-    pub onchange: Callback<String>,
+    pub onchange: Callback<T>,
 
     /// Orientation of the radio buttons group (horizontal or vertical).
     ///
     /// Determines the layout of the radio buttons. The available options are:
     /// - `Orientation::Horizontal`: Displays the radio buttons side by side.
     /// - `Orientation::Vertical`: Stacks the radio buttons vertically.
-    ///   Defaults to `Orientation::Horizontal`.
+    /// - `Orientation::Grid`: Arranges the radio buttons in a grid of equal columns.
+    /// - `Orientation::Responsive`: Switches between orientations at container widths.
+    ///   Defaults to `Orientation::Vertical`.
     #[props(default)]
     pub orientation: Orientation,
 
+    /// Spacing between the radio buttons, on the theme's spacing scale.
+    ///
+    /// Overrides the gap of the orientation, which is `16px` for flex layouts and the gap of a
+    /// `Grid`. Defaults to `None`.
+    #[props(default)]
+    pub gap: Option<Size>,
+
+    /// Cross-axis alignment of the radio buttons.
+    ///
+    /// Rendered as `align-items`. Defaults to `None`, which keeps the browser default.
+    #[props(default)]
+    pub align: Option<Align>,
+
+    /// Main-axis distribution of the radio buttons.
+    ///
+    /// Rendered as `justify-content`. Defaults to `None`, which keeps the browser default.
+    #[props(default)]
+    pub justify: Option<Justify>,
+
+    /// Whether the radio buttons of a horizontal or vertical group wrap onto new lines.
+    ///
+    /// Lets horizontal groups flow onto several rows on narrow screens instead of
+    /// overflowing. Defaults to `false`.
+    #[props(default)]
+    pub wrap: bool,
+
     /// Additional inline styles for the container.
     ///
     /// Allows for custom inline styles to be applied directly to the group container.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(default)]
+    pub style: String,
 
     /// Additional CSS classes for the container.
     ///
     /// Enables adding custom CSS classes to the group container for further styling or
     /// overriding default styles. Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default)]
+    pub class: String,
+
+    /// Accessible name of the group.
+    ///
+    /// Rendered as `aria-label` on the group container. Prefer `aria_labelledby` when the
+    /// group has a visible heading. Omitted when empty.
+    #[props(default)]
+    pub aria_label: String,
+
+    /// ID of the element that labels the group.
+    ///
+    /// Rendered as `aria-labelledby` on the group container, e.g. the `id` of a visible
+    /// heading. Omitted when empty.
+    #[props(default)]
+    pub aria_labelledby: String,
+
+    /// ID of the element that describes the group.
+    ///
+    /// Rendered as `aria-describedby` on the group container. Omitted when empty.
+    #[props(default)]
+    pub aria_describedby: String,
+
+    /// Whether a selection is required.
+    ///
+    /// Exposes `aria-required` on the group and marks every child input as `required`.
+    /// Defaults to `false`.
+    #[props(default = false)]
+    pub required: bool,
+
+    /// Name shared by the `<input>` elements of the group.
+    ///
+    /// Used as the field name when the group is submitted as part of a `<form>`. When
+    /// empty, a unique name is generated so that groups never share a browser radio group.
+    #[props(default)]
+    pub name: String,
+
+    /// ID of the `<form>` the group's inputs belong to.
+    ///
+    /// Only needed when the group is rendered outside of the form element. Omitted when empty.
+    #[props(default)]
+    pub form: String,
+
+    /// Options rendered as `Radio` components.
+    ///
+    /// Lets the group be built from a collection, such as records loaded at runtime. One
+    /// `Radio` is rendered per option, before the children. Defaults to no options.
+    #[props(default)]
+    pub options: Vec<RadioOption<T>>,
+
+    /// Size of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to `Size::XSmall`.
+    #[props(default)]
+    pub size: Size,
+
+    /// Styling type of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to `Type::None`.
+    #[props(default)]
+    pub r#type: Type,
+
+    /// Look of the radio buttons rendered from `options` and of child `Radio`
+    /// components that keep `Variant::Default`.
+    ///
+    /// `Variant::Segmented` renders the group as a segmented control with a sliding
+    /// highlight. Defaults to `Variant::Default`.
+    #[props(default)]
+    pub variant: Variant,
+
+    /// Whether the selected card rendered from `options` shows a check mark.
+    ///
+    /// Only applies to `Variant::Card`. Defaults to `false`.
+    #[props(default)]
+    pub check_mark: bool,
+
+    /// Inline styles for the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub radio_style: String,
+
+    /// CSS class for the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub radio_class: String,
+
+    /// Inline styles applied to the selected radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub selected_style: String,
+
+    /// CSS class applied to the selected radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub selected_class: String,
+
+    /// Inline styles applied to disabled radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub disabled_style: String,
+
+    /// CSS class applied to disabled radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub disabled_class: String,
+
+    /// Inline styles applied to the hovered radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub hover_style: String,
+
+    /// CSS class applied to the hovered radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub hover_class: String,
+
+    /// Inline styles for the descriptions of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub description_style: String,
+
+    /// CSS class for the descriptions of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub description_class: String,
 
     /// Child components for the group.
     ///
     /// This property allows you to pass one or more `Radio` components as children of the
     /// `Group` component. The children will be arranged based on the specified `orientation`.
-    /// Defaults to an empty list of children if not provided.
-    ///
-    /// TODO: Restrict children to `Radio` type and not any Element.
+    /// `Radio` components may be nested inside other elements or components and still
+    /// take part in the group's selection.
     pub children: Element,
 }
 
-// TODO: Fix this 9000 IQ HACK
-fn process_attrs(
-    mut attrs: Box<[Box<[Attribute]>]>,
-    selected: &str,
-    onchange: Callback<String>,
-) -> Box<[Box<[Attribute]>]> {
-    for attribute in &mut attrs {
-        let attr = &mut (*attribute)[0];
-
-        if attr.name == "value" {
-            let is_selected = match &attr.value {
-                AttributeValue::Text(value) => value == selected,
-                _ => false,
-            };
-
-            if is_selected {
-                attr.value = AttributeValue::Bool(true);
-            } else {
-                attr.value = AttributeValue::Bool(false);
+/// Selection state shared by a `Group` with its descendant `Radio` components.
+///
+/// The `Group` provides this through Dioxus context so that every `Radio`
+/// rendered inside it, directly, wrapped in other elements or from a loop,
+/// can read the group state and report clicks and key presses back to the group.
+///
+/// The context is looked up by type, so a `Radio` only joins a `Group` with the same value type.
+struct GroupContext<T: 'static> {
+    state: Signal<RadioGroupState<T>>,
+    onchange: Callback<T>,
+    controlled: Signal<bool>,
+    bind: Signal<Option<Signal<T>>>,
+    name: Signal<String>,
+    form: Signal<String>,
+    variant: Signal<Variant>,
+}
+
+impl<T> Clone for GroupContext<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GroupContext<T> {}
+
+impl<T: RadioValue> GroupContext<T> {
+    /// Applies a transition to the group state, emitting `onchange` when it picks a new value.
+    fn transition(&mut self, f: impl FnOnce(&mut RadioGroupState<T>) -> bool) {
+        let mut state = self.state.peek().clone();
+        let changed = f(&mut state);
+        if !*self.controlled.peek() {
+            if *self.state.peek() != state {
+                self.state.set(state.clone());
+            }
+        } else if let Some(focused) = state.focused() {
+            if !self.state.peek().is_focused(focused) {
+                self.state.write().focus(focused);
             }
         }
-
-        if attr.name == "on_click" {
-            let onclick = {
-                let value = match &attr.value {
-                    AttributeValue::Text(val) => val.clone(),
-                    _ => String::new(),
-                };
-                Callback::new(move |_| {
-                    onchange.call(value.clone());
-                })
-            };
-            attr.value = AttributeValue::Listener(onclick);
+        if changed {
+            if let Some(value) = state.selected() {
+                if let Some(mut bind) = *self.bind.peek() {
+                    bind.set(value.clone());
+                }
+                self.onchange.call(value.clone());
+            }
         }
     }
-    attrs
 }
 
 /// Group Component
@@ -105,26 +311,42 @@ fn process_attrs(
 /// # Properties
 /// The `Group` component uses the `GroupProps` struct for its properties. Key properties include:
 ///
-/// - **selected**: The currently selected value of the group (`String`).
-///   Represents the value of the selected radio button.
-/// - **onchange**: Callback function that is triggered when the selected value changes (`Callback<String>`).
-/// - **orientation**: Defines the layout direction of the group. Can be horizontal or vertical
-///   (`Orientation`). Default: `Orientation::Horizontal`.
+/// - **selected**: The selected value of a controlled group (`Option<T>`). Default: `None`.
+/// - **default_selected**: The initially selected value of an uncontrolled group (`Option<T>`). Default: `None`.
+/// - **bind**: A signal the group reads its selection from and writes new selections into (`Option<Signal<T>>`). Default: `None`.
+/// - **onchange**: Callback function that is triggered when the selected value changes (`Callback<T>`).
+/// - **orientation**: Defines the layout of the group. Can be horizontal, vertical, a grid or responsive
+///   (`Orientation`). Default: `Orientation::Vertical`.
+/// - **gap**: Spacing between the radio buttons (`Option<Size>`). Default: `None`.
+/// - **align**, **justify**: Cross-axis alignment and main-axis distribution (`Option<Align>`, `Option<Justify>`). Default: `None`.
+/// - **wrap**: Whether the radio buttons wrap onto new lines (`bool`). Default: `false`.
 /// - **style**: Custom inline styles applied to the group container (`String`). Default: `""`.
 /// - **class**: CSS class names for the group container (`String`). Default: `""`.
-/// - **children**: A collection of `Radio` components to be rendered inside the `Group`. Each child should be a `Radio` component.
+/// - **aria_label**, **aria_labelledby**, **aria_describedby**: Accessible name and description of the group (`String`). Default: `""`.
+/// - **required**: Whether a selection is required (`bool`). Default: `false`.
+/// - **name**: Name shared by the group's inputs (`String`). Default: a generated unique name.
+/// - **form**: ID of the `<form>` the inputs belong to (`String`). Default: `""`.
+/// - **options**: Options rendered as `Radio` components before the children (`Vec<RadioOption<T>>`). Default: empty.
+/// - **size**, **r#type**, **variant**, **check_mark**: Size, styling type, look and check mark of the radio buttons
+///   rendered from `options` (`Size`, `Type`, `Variant`, `bool`).
+/// - **radio_style**, **radio_class**, **selected_style**, **selected_class**, **disabled_style**, **disabled_class**,
+///   **hover_style**, **hover_class**, **description_style**, **description_class**: Styles and classes of the radio buttons
+///   rendered from `options` (`String`). Default: `""`.
+/// - **children**: The content of the group. Any `Radio` components inside it, at any depth, join the group.
 ///
 /// # Features
 /// - Automatically manages the state of the selected radio button.
 /// - Triggers the provided `onchange` callback with the updated value when the selection changes.
 /// - Supports horizontal or vertical orientation through the `Orientation` enum.
+/// - Typed gap, alignment, justification and wrapping that compose with any orientation.
+/// - A segmented control look through `Variant::Segmented`.
 /// - Highly customizable with inline styles and additional CSS classes.
-/// - Ensures only valid `Radio` components are accepted as children.
+/// - `Radio` components can be wrapped in layout elements or rendered from loops.
 ///
 /// # Examples
 ///
 /// ## Basic Usage
-/// This example demonstrates how to create a simple horizontal radio group:
+/// This example demonstrates how to create a simple radio group:
 ///
 /// ```rust
 /// use dioxus::prelude::*;
@@ -132,15 +354,11 @@ fn process_attrs(
 ///
 /// #[component]
 /// fn App() -> Element {
-///     let mut selected = use_signal(|| "Option1".to_string());
-///     let onchange = {
-///         move |new_value: String| selected.set(new_value)
-///     };
+///     let selected = use_signal(|| "Option1");
 ///
 ///     rsx! {
 ///         Group {
-///             selected: selected(),
-///             onchange: onchange,
+///             bind: selected,
 ///             Radio { value: "Option1", label: "Option 1" }
 ///             Radio { value: "Option2", label: "Option 2" }
 ///             Radio { value: "Option3", label: "Option 3" }
@@ -149,6 +367,78 @@ fn process_attrs(
 /// }
 /// ```
 ///
+/// ## Grid Layout
+/// A 3-column plan picker that collapses to a single column on narrow containers:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::{Group, Radio};
+/// use radiors::Orientation;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Group::<&str> {
+///             default_selected: "basic",
+///             orientation: Orientation::responsive([
+///                 (0, Orientation::Vertical),
+///                 (640, Orientation::grid(3)),
+///             ]),
+///             Radio { value: "basic", label: "Basic" }
+///             Radio { value: "pro", label: "Pro" }
+///             Radio { value: "enterprise", label: "Enterprise" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Wrapping Layout
+/// A horizontal group that wraps onto new rows instead of overflowing narrow screens:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::{Group, Radio};
+/// use radiors::{Align, Orientation, Size};
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Group::<&str> {
+///             default_selected: "red",
+///             orientation: Orientation::Horizontal,
+///             gap: Size::Small,
+///             align: Align::Center,
+///             wrap: true,
+///             Radio { value: "red", label: "Red" }
+///             Radio { value: "green", label: "Green" }
+///             Radio { value: "blue", label: "Blue" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Segmented Control
+/// A compact row of joined segments with a highlight that slides to the selection:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::{Group, Radio};
+/// use radiors::Variant;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Group::<&str> {
+///             default_selected: "week",
+///             variant: Variant::Segmented,
+///             Radio { value: "day", label: "Day" }
+///             Radio { value: "week", label: "Week" }
+///             Radio { value: "month", label: "Month" }
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Horizontal Orientation
 /// Use the `orientation` property to arrange the group horizontally:
 ///
@@ -159,15 +449,11 @@ fn process_attrs(
 ///
 /// #[component]
 /// fn App() -> Element {
-///     let mut selected = use_signal(|| "Option1".to_string());
-///     let onchange = {
-///         move |new_value: String| selected.set(new_value)
-///     };
+///     let selected = use_signal(|| "Option1");
 ///
 ///     rsx! {
 ///         Group {
-///             selected: selected(),
-///             onchange: onchange,
+///             bind: selected,
 ///             orientation: Orientation::Horizontal,
 ///             style: "margin: 20px;",
 ///             Radio { value: "Option1", label: "Option 1" }
@@ -178,6 +464,73 @@ fn process_attrs(
 /// }
 /// ```
 ///
+/// ## Typed Values
+/// `Group` and `Radio` are generic over their value type. Any `Clone + PartialEq + Display`
+/// type, such as an enum, can be bound directly:
+///
+/// ```rust
+/// use std::fmt;
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::{Group, Radio};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Plan {
+///     Basic,
+///     Pro,
+/// }
+///
+/// impl fmt::Display for Plan {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str(match self {
+///             Plan::Basic => "basic",
+///             Plan::Pro => "pro",
+///         })
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> Element {
+///     let plan = use_signal(|| Plan::Basic);
+///
+///     rsx! {
+///         Group {
+///             bind: plan,
+///             Radio { value: Plan::Basic, label: "Basic" }
+///             Radio { value: Plan::Pro, label: "Pro" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Options from Data
+/// Groups loaded at runtime can be rendered from a collection of `RadioOption`s instead of
+/// hand-written `Radio` children:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::Group;
+/// use radiors::{RadioOption, Size, Type};
+///
+/// #[component]
+/// fn Shipping(methods: Vec<(u32, String, bool)>) -> Element {
+///     let mut selected = use_signal(|| None::<u32>);
+///     let options = methods
+///         .iter()
+///         .map(|(id, name, available)| RadioOption::new(*id, name.clone()).with_disabled(!available))
+///         .collect::<Vec<_>>();
+///
+///     rsx! {
+///         Group {
+///             selected: selected(),
+///             onchange: move |id: u32| selected.set(Some(id)),
+///             options: options,
+///             size: Size::Medium,
+///             r#type: Type::Primary,
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Custom Styling
 /// Customize the appearance of the group container using `style` and `class`:
 ///
@@ -188,8 +541,8 @@ fn process_attrs(
 /// #[component]
 /// fn App() -> Element {
 ///     rsx! {
-///         Group {
-///             selected: "Option1",
+///         Group::<&str> {
+///             default_selected: "Option1",
 ///             style: "border: 1px solid black; padding: 10px;",
 ///             class: "custom-radio-group",
 ///             Radio { value: "Option1", label: "Option 1" }
@@ -200,34 +553,204 @@ fn process_attrs(
 /// ```
 ///
 /// # Behavior
-/// - Clicking a `Radio` component updates the `selected` value in the `Group`.
-/// - The `onchange` callback is triggered with the `value` of the selected `Radio` as a `String`.
+/// - Clicking a `Radio` component triggers the `onchange` callback with its `value`.
+/// - `selected`, `default_selected` and `bind` follow the selection rules of the [`common`](crate::common) module.
 /// - When `orientation` is set to `Orientation::Vertical`, the child components are stacked vertically.
+/// - A responsive orientation observes the width of the container and applies the matching layout.
+/// - `gap`, `align`, `justify` and `wrap` are applied on top of the resolved orientation.
+/// - Inside a `ThemeProvider`, the container carries the theme as `--radiors-*` CSS variables.
 /// - Inline styles and CSS classes allow fine-grained control of the component's appearance.
+/// - The inputs share the group's `name`, so the selection is submitted with its `<form>`, and resetting the form resets the group.
+/// - The container is rendered with `role="radiogroup"` and each `Radio` with `role="radio"` and `aria-checked`.
+/// - Only one radio button is in the tab order at a time: the selected one, or the first enabled one.
+/// - Arrow keys select the next or previous enabled radio button, wrapping at the ends, while `Home`
+///   and `End` jump to the first and last enabled one. `Space` selects the focused radio button.
 ///
 /// # Notes
-/// - TODO: The `children` property must contain only `Radio` components; other elements will cause runtime errors.
+/// - Selection is shared through context, so a `Radio` outside of any `Group` falls back to its own `selected` prop.
 /// - The `selected` property must match one of the `value` attributes in the `Radio` components for proper behavior.
+/// - A `Radio` only joins a `Group` of the same value type, e.g. `&'static str` literals do not join a `Group`
+///   whose `selected` value is a `String`. When it cannot be inferred, spell the type out as `Group::<&str> { .. }`.
+/// - Text properties such as `style`, `class`, `aria_label` and `name` accept string literals, formatted strings and owned `String`s.
 #[component]
-pub fn Group(props: GroupProps) -> Element {
+pub fn Group<T: RadioValue>(props: GroupProps<T>) -> Element {
+    let mut state = use_signal(|| {
+        let mut state = RadioGroupState::default();
+        if let Some(selected) = props
+            .bind
+            .map(|bind| bind.peek().clone())
+            .or_else(|| props.selected.peek().clone())
+            .or_else(|| props.default_selected.clone())
+        {
+            state = state.with_selected(selected);
+        }
+        // The `options` are known before their radios register, which lets the first
+        // render, e.g. on the server, already place the segmented highlight.
+        for option in &props.options {
+            state.register(option.value.clone(), option.disabled);
+        }
+        state
+    });
+    let generated_name = use_hook(|| std::rc::Rc::new(LazyId::group_name(root_ids())));
+    let name = match props.name.as_str() {
+        "" => generated_name.get().to_string(),
+        name => name.to_string(),
+    };
+    let mut group = use_context_provider(|| GroupContext {
+        state,
+        onchange: props.onchange,
+        controlled: Signal::new(props.bind.is_some() || props.selected.peek().is_some()),
+        bind: Signal::new(props.bind),
+        name: Signal::new(name.clone()),
+        form: Signal::new(props.form.clone()),
+        variant: Signal::new(props.variant),
+    });
+    let mut reset_listener = use_signal(|| None::<FormResetListener>);
+    let mut container = use_signal(|| None::<web_sys::Element>);
+    let width = use_signal(|| None::<u32>);
+    let mut width_listener = use_signal(|| None::<WidthListener>);
+    let form = props.form.clone();
+    use_effect(use_reactive!(|form| {
+        let on_reset = Runtime::wrap_closure(move |_: ()| {
+            let mut group = group;
+            group.transition(|state| state.reset());
+        });
+        let listener = container
+            .read()
+            .as_ref()
+            .and_then(|container| FormResetListener::new(container, &form, move || on_reset(())));
+        reset_listener.set(listener);
+    }));
+    let responsive = props.orientation.is_responsive();
+    use_effect(use_reactive!(|responsive| {
+        let on_resize = Runtime::wrap_closure(move |value: u32| {
+            let mut width = width;
+            width.set(Some(value));
+        });
+        let listener = container
+            .read()
+            .as_ref()
+            .filter(|_| responsive)
+            .and_then(|container| WidthListener::new(container, on_resize));
+        width_listener.set(listener);
+    }));
+    let layout = GroupLayout {
+        variant: props.variant,
+        r#type: &props.r#type,
+        orientation: props.orientation.resolve(width()),
+        gap: props.gap.as_ref(),
+        align: props.align,
+        justify: props.justify,
+        wrap: props.wrap,
+    };
+    let layout_class = layout.to_class();
+    let layout_style = layout.to_style();
+    let theme_style = try_use_context::<ReadOnlySignal<Theme>>()
+        .map(|theme| theme.read().to_style())
+        .unwrap_or_default();
+
+    let selected = match props.bind {
+        Some(bind) => Some(bind.read().clone()),
+        None => props.selected.read().clone(),
+    };
+    // Once `selected` has held a value, the group stays controlled, so setting it back to
+    // `None` clears the selection instead of handing it back to the group.
+    let controlled = *group.controlled.peek() || selected.is_some();
+    if *group.controlled.peek() != controlled
+        || *group.bind.peek() != props.bind
+        || *group.name.peek() != name
+        || *group.form.peek() != props.form
+        || *group.variant.peek() != props.variant
+    {
+        signal_write_in_component_body::allow(|| {
+            group.controlled.set(controlled);
+            group.bind.set(props.bind);
+            group.name.set(name);
+            group.form.set(props.form.clone());
+            group.variant.set(props.variant);
+        });
+    }
+
+    if (controlled && state.peek().selected() != selected.as_ref())
+        || state.peek().is_required() != props.required
+    {
+        signal_write_in_component_body::allow(|| {
+            let mut state = state.write();
+            if controlled {
+                state.set_selected(selected);
+            }
+            state.set_required(props.required);
+        });
+    }
+
+    let highlight = (props.variant == Variant::Segmented)
+        .then(|| segment_highlight_style(&state.read()))
+        .flatten();
+    let option_type = match props.variant {
+        Variant::Segmented => Type::None,
+        _ => props.r#type.clone(),
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let key = e.key().to_string();
+        let mut handled = false;
+        group.transition(|state| {
+            let changed = state.handle_key(&key);
+            handled = changed.is_some();
+            changed.unwrap_or(false)
+        });
+        if handled {
+            e.prevent_default();
+        }
+    };
+
     rsx! {
         div {
-            class: "{props.class}",
-            style: "{props.orientation.to_style()} {props.style}",
-            for child in props.children.iter() {
-                {
-                    VNode::new(
-                        child.key.clone(),
-                        child.template,
-                        child.dynamic_nodes.clone(),
-                        process_attrs(
-                            child.dynamic_attrs.clone(),
-                            &props.selected,
-                            props.onchange
-                        )
-                    )
+            role: "radiogroup",
+            aria_label: non_empty(&props.aria_label),
+            aria_labelledby: non_empty(&props.aria_labelledby),
+            aria_describedby: non_empty(&props.aria_describedby),
+            aria_required: props.required.then_some("true"),
+            class: class_list([&layout_class, &props.class]),
+            style: inline_style([&theme_style, &layout_style, &props.style]),
+            onkeydown: onkeydown,
+            onmounted: move |e: MountedEvent| {
+                if let Some(element) = e.data().downcast::<web_sys::Element>() {
+                    container.set(Some(element.clone()));
+                }
+            },
+            if let Some(style) = highlight {
+                span {
+                    aria_hidden: "true",
+                    style: style,
+                    class: class_list([SEGMENT_HIGHLIGHT_CLASS]),
+                }
+            }
+            for option in props.options.iter() {
+                Radio {
+                    key: "{option.value.to_value()}",
+                    value: option.value.clone(),
+                    label: option.label.clone(),
+                    src: option.src.clone(),
+                    description: option.description.clone(),
+                    disabled: option.disabled,
+                    size: props.size.clone(),
+                    r#type: option_type.clone(),
+                    variant: props.variant,
+                    check_mark: props.check_mark,
+                    style: props.radio_style.clone(),
+                    class: props.radio_class.clone(),
+                    selected_style: props.selected_style.clone(),
+                    selected_class: props.selected_class.clone(),
+                    disabled_style: props.disabled_style.clone(),
+                    disabled_class: props.disabled_class.clone(),
+                    hover_style: props.hover_style.clone(),
+                    hover_class: props.hover_class.clone(),
+                    description_style: props.description_style.clone(),
+                    description_class: props.description_class.clone(),
                 }
             }
+            {props.children}
         }
     }
 }
@@ -237,70 +760,106 @@ pub fn Group(props: GroupProps) -> Element {
 /// The `Radio` component allows the creation of individual, customizable radio buttons.
 /// It supports various configurations for appearance, behavior, and styles.
 #[derive(Props, PartialEq, Clone)]
-pub struct RadioProps {
+pub struct RadioProps<T: RadioValue> {
     /// The label for the radio button.
     ///
     /// Defines the text displayed next to the radio button.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub label: &'static str,
+    #[props(default)]
+    pub label: String,
 
     /// The value for the radio button.
     ///
     /// This value represents the data associated with the radio button, used to identify
-    /// it in the `Group`'s selection context.
-    /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub value: &'static str,
+    /// it in the `Group`'s selection context. Its string form, given by `RadioValue::to_value`,
+    /// is used as the `value` attribute of the underlying `<input>`.
+    pub value: T,
 
     /// Optional image URL for the radio button.
     ///
     /// If provided, the radio button can display an image along with its label.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub src: &'static str,
+    #[props(default)]
+    pub src: String,
+
+    /// Secondary text for the radio button.
+    ///
+    /// Rendered under the label, e.g. to explain the option in more detail, and referenced
+    /// by the radio button's `aria-describedby`. Omitted when empty.
+    #[props(default)]
+    pub description: String,
+
+    /// Inline styles for the description.
+    ///
+    /// Applied after the built-in description styles. Defaults to an empty string if not provided.
+    #[props(default)]
+    pub description_style: String,
+
+    /// CSS class for the description.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub description_class: String,
+
+    /// Content rendered before the label, such as an icon.
+    ///
+    /// Defaults to no content.
+    #[props(default = VNode::empty())]
+    pub leading: Element,
+
+    /// Content rendered after the label, such as a badge or a price.
+    ///
+    /// Defaults to no content.
+    #[props(default = VNode::empty())]
+    pub trailing: Element,
+
+    /// Rich label content.
+    ///
+    /// Rendered inside the label element after the `label` text, so formatted text can
+    /// replace or extend it. Defaults to no content.
+    pub children: Element,
 
     /// Inline styles for the container.
     ///
     /// Allows applying custom inline CSS styles directly to the radio button's container.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(default)]
+    pub style: String,
 
     /// CSS class for the container.
     ///
     /// Adds custom CSS classes to style the radio button's container.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default)]
+    pub class: String,
 
     /// Inline styles for the label.
     ///
     /// Enables the addition of custom inline CSS styles to the label text.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub label_style: &'static str,
+    #[props(default)]
+    pub label_style: String,
 
     /// CSS class for the label.
     ///
     /// Adds custom CSS classes to style the radio button's label.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub label_class: &'static str,
+    #[props(default)]
+    pub label_class: String,
 
     /// Inline styles for the image.
     ///
     /// Allows applying custom inline CSS styles to the image associated with the radio button.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub image_style: &'static str,
+    #[props(default)]
+    pub image_style: String,
 
     /// CSS class for the image.
     ///
     /// Adds custom CSS classes to style the image associated with the radio button.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub image_class: &'static str,
+    #[props(default)]
+    pub image_class: String,
 
     /// The size of the radio button.
     ///
@@ -320,6 +879,19 @@ pub struct RadioProps {
     #[props(default)]
     pub r#type: Type,
 
+    /// The look of the radio button.
+    ///
+    /// `Variant::Card` renders a bordered card for plan and product pickers.
+    /// Defaults to `Variant::Default` if not provided.
+    #[props(default)]
+    pub variant: Variant,
+
+    /// Whether a selected card shows a check mark in its corner.
+    ///
+    /// Only applies to `Variant::Card`. Defaults to `false`.
+    #[props(default)]
+    pub check_mark: bool,
+
     /// Whether this radio button is selected.
     ///
     /// Indicates if the radio button is currently selected. Managed internally in the `Group`
@@ -334,78 +906,131 @@ pub struct RadioProps {
     #[props(default = false)]
     pub disabled: bool,
 
+    /// Whether a selection is required.
+    ///
+    /// Marks the underlying `<input>` as `required`. Inside a `Group`, the group's
+    /// `required` property also applies. Defaults to `false`.
+    #[props(default = false)]
+    pub required: bool,
+
+    /// Name of the underlying `<input>`.
+    ///
+    /// Used as the field name when submitted as part of a `<form>`. Inside a `Group`,
+    /// the group's name is used instead. Omitted when empty.
+    #[props(default)]
+    pub name: String,
+
+    /// ID of the `<form>` the underlying `<input>` belongs to.
+    ///
+    /// Inside a `Group`, defaults to the group's `form`. Omitted when empty.
+    #[props(default)]
+    pub form: String,
+
     /// Inline styles applied when the radio button is selected.
     ///
     /// Provides custom styles for the radio button in the selected state.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub selected_style: &'static str,
+    #[props(default)]
+    pub selected_style: String,
 
     /// CSS class applied when the radio button is selected.
     ///
     /// Adds custom CSS classes to style the radio button in the selected state.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub selected_class: &'static str,
+    #[props(default)]
+    pub selected_class: String,
 
     /// Inline styles applied when the radio button is disabled.
     ///
     /// Provides custom styles for the radio button in the disabled state.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub disabled_style: &'static str,
+    #[props(default)]
+    pub disabled_style: String,
 
     /// CSS class applied when the radio button is disabled.
     ///
     /// Adds custom CSS classes to style the radio button in the disabled state.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub disabled_class: &'static str,
+    #[props(default)]
+    pub disabled_class: String,
 
     /// Inline styles for animations applied to the radio button.
     ///
     /// Enables applying custom styles to animations or transitions for the radio button.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub animation_style: &'static str,
+    #[props(default)]
+    pub animation_style: String,
 
     /// CSS class for animations applied to the radio button.
     ///
     /// Adds custom CSS classes to handle animations or transitions for the radio button.
     /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub animation_class: &'static str,
+    #[props(default)]
+    pub animation_class: String,
+
+    /// Inline styles applied while the pointer is over the radio button.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub hover_style: String,
+
+    /// CSS class applied while the pointer is over the radio button.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub hover_class: String,
+
+    /// Inline styles applied while the radio button is being pressed.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub active_style: String,
+
+    /// CSS class applied while the radio button is being pressed.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub active_class: String,
+
+    /// Inline styles applied while the radio button has keyboard focus.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub focus_style: String,
+
+    /// CSS class applied while the radio button has keyboard focus.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[props(default)]
+    pub focus_class: String,
 
     /// Inline styles for the hidden input element.
     ///
     /// Provides custom styles for the hidden `<input>` element used for the radio button.
     /// Defaults to `HIDDEN_INPUT_STYLE`.
-    #[props(default = HIDDEN_INPUT_STYLE)]
-    pub input_style: &'static str,
+    #[props(default = HIDDEN_INPUT_STYLE.to_string())]
+    pub input_style: String,
 
     /// CSS class for the hidden input element.
     ///
     /// Adds custom CSS classes for the hidden `<input>` element.
-    /// Defaults to an empty string if not provided.
-    #[props(default = "")]
-    pub input_class: &'static str,
+    /// Defaults to `radiors-radio__input` with the `stylesheet` feature, and to an empty string otherwise.
+    #[props(default = HIDDEN_INPUT_CLASS.to_string())]
+    pub input_class: String,
 
     /// Callback for when the radio button is clicked.
     ///
     /// Triggered whenever the user clicks on the radio button. It provides the `value` of the
-    /// radio button as a string to the callback.
+    /// radio button to the callback.
     /// Defaults to a no-op.
     #[props(default)]
-    pub onclick: Callback<String>,
-
-    /// Internal callback triggered when the radio button is clicked.
-    ///
-    /// This property is intended for use by the parent `Group` component to manage
-    /// the state of the radio group. It is not exposed for direct use by end users.
-    ///
-    /// The callback receives the `value` of the clicked radio button as a `String`.
-    #[props(default)]
-    pub on_click: Callback<String>,
+    pub onclick: Callback<T>,
 }
 
 /// Radio Component
@@ -418,12 +1043,18 @@ pub struct RadioProps {
 /// The `Radio` component uses the `RadioProps` struct for its properties. Key properties include:
 ///
 /// - **label**: The text displayed next to the radio button (`String`). Default: `""`.
-/// - **value**: The value associated with the radio button (`String`). Default: `""`.
+/// - **value**: The value associated with the radio button (`T`). Required.
 /// - **selected**: Indicates if the radio button is selected (`bool`). Default: `false`.
 /// - **disabled**: Whether the radio button is disabled (`bool`). Default: `false`.
-/// - **on_click**: A callback triggered when the radio button is clicked (`Callback<String>`). Default: no-op.
-/// - **onclick**: A secondary callback triggered when the radio button is clicked (`Callback<String>`). Default: no-op.
+/// - **required**: Whether the underlying input is required (`bool`). Default: `false`.
+/// - **name**: Name of the underlying input (`String`). Default: `""`, or the group's name inside a `Group`.
+/// - **form**: ID of the `<form>` the input belongs to (`String`). Default: `""`.
+/// - **onclick**: A callback triggered when the radio button is clicked (`Callback<T>`). Default: no-op.
 /// - **src**: URL of an optional image displayed alongside the radio button (`String`). Default: `""`.
+/// - **description**: Secondary text rendered under the label (`String`). Default: `""`.
+/// - **description_style**, **description_class**: Styles and classes of the description (`String`). Default: `""`.
+/// - **leading**, **trailing**: Content rendered before and after the label (`Element`). Default: empty.
+/// - **children**: Rich label content rendered after the `label` text (`Element`). Default: empty.
 /// - **style**: Custom inline styles for the container (`String`). Default: `""`.
 /// - **class**: CSS class for the container (`String`). Default: `""`.
 /// - **input_style**: Inline styles for the `<input>` element (`String`). Default: `""`.
@@ -433,16 +1064,23 @@ pub struct RadioProps {
 /// - **disabled_style**: Custom styles applied when the radio button is disabled (`String`). Default: `""`.
 /// - **disabled_class**: CSS class applied when the radio button is disabled (`String`). Default: `""`.
 /// - **animation_style**: Inline styles for animations (`String`). Default: `""`.
+/// - **hover_style**, **hover_class**: Styles and classes applied while hovered (`String`). Default: `""`.
+/// - **active_style**, **active_class**: Styles and classes applied while pressed (`String`). Default: `""`.
+/// - **focus_style**, **focus_class**: Styles and classes applied while focused (`String`). Default: `""`.
 /// - **image_style**: Inline styles for the optional image (`String`). Default: `""`.
 /// - **image_class**: CSS class for the optional image (`String`). Default: `""`.
 /// - **label_style**: Inline styles for the label text (`String`). Default: `""`.
 /// - **label_class**: CSS class for the label text (`String`). Default: `""`.
 /// - **size**: Determines the size of the radio button. Uses the `Size` enum (`Size::Small`, `Size::Medium`, `Size::Large`). Default: `Size::XSmall`.
 /// - **r#type**: Determines the type/style of the radio button. Uses the `Type` enum (e.g., `Type::Primary`, `Type::Secondary`). Default: `Type::None`.
+/// - **variant**: The look of the radio button (`Variant`). Default: `Variant::Default`.
+/// - **check_mark**: Whether a selected card shows a check mark (`bool`). Default: `false`.
 ///
 /// # Features
 /// - Provides extensive customization for styles and classes.
 /// - Optional image display alongside the label.
+/// - Rich content through the `leading` and `trailing` slots and children.
+/// - A card look for plan and product pickers through `Variant::Card`.
 /// - Supports selected and disabled states.
 /// - Works with callbacks for handling user interactions.
 /// - Designed to integrate into the `Group` component for managing grouped radio buttons.
@@ -501,6 +1139,68 @@ pub struct RadioProps {
 /// }
 /// ```
 ///
+/// ## Rich Content
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::Radio;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Radio {
+///             value: "express",
+///             leading: rsx! { i { class: "icon-truck" } },
+///             trailing: rsx! { strong { "$9.99" } },
+///             "Express "
+///             span { class: "badge", "Fastest" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Card
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::Radio;
+/// use radiors::{Size, Variant};
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Radio {
+///             value: "pro",
+///             label: "Pro",
+///             description: "For growing teams",
+///             trailing: rsx! { strong { "$29/mo" } },
+///             variant: Variant::Card,
+///             size: Size::Large,
+///             selected: true,
+///             check_mark: true,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Hover, Active and Focus States
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::Radio;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Radio {
+///             label: "Option 1",
+///             value: "Option1",
+///             animation_style: "transition: background-color 0.2s;",
+///             hover_style: "background-color: #f0f0f0;",
+///             active_class: "pressed",
+///             focus_style: "outline: 2px solid #007bff;",
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Disabled Radio Button
 /// ```rust
 /// use dioxus::prelude::*;
@@ -518,79 +1218,392 @@ pub struct RadioProps {
 /// }
 /// ```
 ///
+/// ## Runtime Labels and Classes
+/// Text properties are `String`s, so they can be formatted from runtime data:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::Radio;
+///
+/// #[component]
+/// fn Plan(name: String, price: u32) -> Element {
+///     rsx! {
+///         Radio {
+///             label: "{name} (${price}/month)",
+///             value: name.clone(),
+///             class: format!("plan-{}", name.to_lowercase()),
+///         }
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - The `onclick` callback is triggered when the radio button is clicked, passing its `value`.
 /// - If the `disabled` property is set to `true`, the button will not respond to clicks or emit callbacks.
 /// - When selected, the radio button applies the `selected_style` and `selected_class`.
 /// - Similarly, when disabled, the button applies the `disabled_style` and `disabled_class`.
+/// - Hovering, pressing and focusing the radio button add the `hover_*`, `active_*` and `focus_*` styles
+///   and classes after the other ones, so they take precedence. Disabled radio buttons ignore them.
+/// - A non-empty `description` is rendered with a generated `id` that the radio button's
+///   `aria-describedby` refers to, so assistive technology announces it after the label.
+/// - Clicks on `leading`, `trailing` and children content select the radio button like clicks on
+///   its label, and their text is part of the radio button's accessible name.
 ///
 /// # Notes
-/// - The `selected` and `on_click` properties are typically controlled by the parent `Group` component.
+/// - Inside a `Group`, the selected state comes from the group's context and the `selected` property is ignored.
 /// - If an image is provided via the `src` property, it will be rendered next to the label with optional custom styles and classes.
 /// - The component uses the `Size` and `Type` enums for additional flexibility in appearance and behavior.
+/// - Text properties such as `label`, `src`, `style` and `class` accept string literals, formatted strings and owned `String`s.
 ///
 /// TODO: Add animations
 #[component]
-pub fn Radio(props: RadioProps) -> Element {
-    let onclick = {
-        let value = props.value.to_string();
-        move |e: MouseEvent| {
-            e.stop_propagation();
-            if !props.disabled {
-                props.on_click.call(value.clone());
-                props.onclick.call(value.clone());
+pub fn Radio<T: RadioValue>(props: RadioProps<T>) -> Element {
+    let group = try_use_context::<GroupContext<T>>();
+    let value = props.value.clone();
+    // The radio joins its group once when it mounts, before it reads its tab stop below, so
+    // that the first render, e.g. on the server, already knows the radios rendered before
+    // it. Later changes of `value` and `disabled` are synced from an effect, and the radio
+    // leaves the group when it is dropped.
+    let registered = use_hook(|| {
+        if let Some(mut group) = group {
+            // Runs once per radio, so the write cannot start a render loop.
+            signal_write_in_component_body::allow(|| {
+                group.state.write().register(value.clone(), props.disabled)
+            });
+        }
+        std::rc::Rc::new(std::cell::RefCell::new(value.clone()))
+    });
+    use_drop({
+        let registered = registered.clone();
+        move || {
+            if let Some(mut group) = group {
+                if let Ok(mut state) = group.state.try_write() {
+                    state.unregister(&registered.borrow());
+                }
+            }
+        }
+    });
+    let disabled = props.disabled;
+    use_effect(use_reactive!(|value, disabled| {
+        let Some(mut group) = group else {
+            return;
+        };
+        let previous = registered.replace(value.clone());
+        let synced = previous == value && {
+            let state = group.state.peek();
+            state.options().contains(&value) && state.is_disabled(&value) == disabled
+        };
+        if !synced {
+            let mut state = group.state.write();
+            if previous != value {
+                state.unregister(&previous);
+            }
+            state.register(value, disabled);
+        }
+    }));
+    let name = group.map_or(props.name.clone(), |group| group.name.cloned());
+    let form = match (group, props.form.as_str()) {
+        (Some(group), "") => group.form.cloned(),
+        _ => props.form.clone(),
+    };
+    let (selected, tab_stop, required) = match group {
+        Some(group) => {
+            let state = group.state.read();
+            (
+                state.is_selected(&value),
+                state.is_tab_stop_while_registering(&value),
+                props.required || state.is_required(),
+            )
+        }
+        None => (props.selected, true, props.required),
+    };
+
+    let description_id = use_hook(|| std::rc::Rc::new(LazyId::description(root_ids())));
+    let description_id = (!props.description.is_empty()).then(|| description_id.get().to_string());
+    let mut element = use_signal(|| None::<std::rc::Rc<MountedData>>);
+    let mut hovered = use_signal(|| false);
+    let mut active = use_signal(|| false);
+    let mut has_focus = use_signal(|| false);
+    let focused = use_memo({
+        let value = value.clone();
+        move || group.is_some_and(|group| group.state.read().is_focused(&value))
+    });
+    use_effect(move || {
+        if focused() {
+            if let Some(element) = element() {
+                spawn(async move {
+                    let _ = element.set_focus(true).await;
+                });
             }
         }
+    });
+
+    let onclick = move |_: MouseEvent| {
+        if !props.disabled {
+            if let Some(mut group) = group {
+                group.transition(|state| state.select(&value));
+            }
+            props.onclick.call(value.clone());
+        }
     };
 
-    let selected_style = if props.selected {
-        props.selected_style
+    let selected_style = if selected {
+        props.selected_style.as_str()
     } else {
         ""
     };
     let disabled_style = if props.disabled {
-        props.disabled_style
+        props.disabled_style.as_str()
     } else {
         ""
     };
-    let selected_class = if props.selected {
-        props.selected_class
+    let selected_class = if selected {
+        props.selected_class.as_str()
     } else {
         ""
     };
     let disabled_class = if props.disabled {
-        props.disabled_class
+        props.disabled_class.as_str()
     } else {
         ""
     };
 
+    let interactive = !props.disabled;
+    let (hover_style, hover_class) = if hovered() && interactive {
+        (props.hover_style.as_str(), props.hover_class.as_str())
+    } else {
+        ("", "")
+    };
+    let (active_style, active_class) = if active() && interactive {
+        (props.active_style.as_str(), props.active_class.as_str())
+    } else {
+        ("", "")
+    };
+    let (focus_style, focus_class) = if has_focus() && interactive {
+        (props.focus_style.as_str(), props.focus_class.as_str())
+    } else {
+        ("", "")
+    };
+    let themed_style = themed_radio_style(selected, has_focus() && interactive);
+    let variant = effective_variant(
+        props.variant,
+        group.map_or(Variant::Default, |group| group.variant.cloned()),
+    );
+    let builtin_class = radio_class(
+        &variant,
+        &props.r#type,
+        &props.size,
+        selected,
+        props.disabled,
+    );
+    let check_mark = variant == Variant::Card && props.check_mark && selected;
+    let variant = variant_style(&variant, selected);
+
     rsx! {
         div {
-            class: "{selected_class} {disabled_class} {props.class}",
-            style: "{selected_style} {disabled_style} {props.style} {props.animation_style} {props.r#type.to_style()} {props.size.to_style()}",
+            role: "radio",
+            aria_checked: if selected { "true" } else { "false" },
+            aria_disabled: props.disabled.then_some("true"),
+            aria_describedby: description_id.clone(),
+            tabindex: if tab_stop && !props.disabled { "0" } else { "-1" },
+            class: class_list([
+                builtin_class.as_str(),
+                selected_class,
+                disabled_class,
+                &props.class,
+                &props.animation_class,
+                hover_class,
+                focus_class,
+                active_class,
+            ]),
+            style: inline_style([
+                themed_style.as_str(),
+                &variant,
+                selected_style,
+                disabled_style,
+                &props.style,
+                &props.animation_style,
+                &type_style(&props.r#type),
+                &size_style(&props.size),
+                hover_style,
+                focus_style,
+                active_style,
+            ]),
             onclick: onclick,
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| {
+                hovered.set(false);
+                active.set(false);
+            },
+            onmousedown: move |_| active.set(true),
+            onmouseup: move |_| active.set(false),
+            onfocus: move |_| has_focus.set(true),
+            onblur: move |_| has_focus.set(false),
+            onmounted: move |e: MountedEvent| element.set(Some(e.data())),
             input {
                 r#type: "radio",
-                name: "radio",
-                value: "{props.value}",
-                checked: "{props.selected}",
-                disabled: "{props.disabled}",
-                style: "{props.input_style}",
-                class: "{props.input_class}",
+                name: non_empty(&name),
+                form: non_empty(&form),
+                value: props.value.to_value(),
+                checked: selected,
+                disabled: props.disabled,
+                required: required,
+                tabindex: "-1",
+                aria_hidden: "true",
+                style: inline_style([&props.input_style]),
+                class: class_list([&props.input_class]),
             },
+            if check_mark {
+                span {
+                    aria_hidden: "true",
+                    style: inline_style([CHECK_MARK_STYLE]),
+                    class: class_list([CHECK_MARK_CLASS]),
+                    "✓"
+                }
+            }
             if !props.src.is_empty() {
                 img {
                     src: "{props.src}",
                     alt: "{props.label}",
-                    style: "{props.image_style}",
-                    class: "{props.image_class}",
+                    style: inline_style([&props.image_style]),
+                    class: class_list([&props.image_class]),
                 }
             },
+            {props.leading}
             span {
-                style: "{props.label_style}",
-                class: "{props.label_class}",
+                style: inline_style([&props.label_style]),
+                class: class_list([&props.label_class]),
                 "{props.label}"
+                {props.children}
+            }
+            {props.trailing}
+            if let Some(id) = &description_id {
+                span {
+                    id: "{id}",
+                    style: inline_style([DESCRIPTION_STYLE, &props.description_style]),
+                    class: class_list([DESCRIPTION_CLASS, &props.description_class]),
+                    "{props.description}"
+                }
             }
         }
     }
 }
+
+/// Properties for configuring the `ThemeProvider` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct ThemeProviderProps {
+    /// The light theme provided to every `Group` below this component.
+    pub theme: ReadOnlySignal<Theme>,
+
+    /// The dark theme provided to every `Group` below this component.
+    ///
+    /// Defaults to `Theme::dark()`.
+    #[props(default = ReadOnlySignal::new(Signal::new(Theme::dark())))]
+    pub dark_theme: ReadOnlySignal<Theme>,
+
+    /// Which of `theme` and `dark_theme` is applied.
+    ///
+    /// Defaults to `ColorScheme::System`, which follows the browser's `prefers-color-scheme`.
+    #[props(default)]
+    pub color_scheme: ReadOnlySignal<ColorScheme>,
+
+    /// Content rendered inside the provider.
+    pub children: Element,
+}
+
+/// ThemeProvider Component
+///
+/// Provides a `Theme` to its descendants through Dioxus context. Every `Group` rendered
+/// below it emits the theme as `--radiors-*` CSS variables on its container, so
+/// changing the `theme` property restyles all of them at once.
+///
+/// # Properties
+/// - **theme**: The light `Theme` to provide. Accepts a value or a signal.
+/// - **dark_theme**: The dark `Theme` to provide. Default: `Theme::dark()`.
+/// - **color_scheme**: Whether to apply the light or the dark theme, or to follow the browser's
+///   `prefers-color-scheme` preference. Default: `ColorScheme::System`.
+/// - **children**: Content rendered inside the provider.
+///
+/// # Examples
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::{Group, Radio, ThemeProvider};
+/// use radiors::{Swatch, Theme, Type};
+///
+/// #[component]
+/// pub fn App() -> Element {
+///     let theme = Theme {
+///         primary: Swatch::new("#6d28d9", "#ffffff"),
+///         radius: "8px".into(),
+///         ..Theme::default()
+///     };
+///
+///     rsx! {
+///         ThemeProvider {
+///             theme: theme,
+///             Group::<&str> {
+///                 default_selected: "Option1",
+///                 Radio { value: "Option1", label: "Option 1", r#type: Type::Primary }
+///                 Radio { value: "Option2", label: "Option 2", r#type: Type::Primary }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Forced Dark Mode
+/// ```rust
+/// use dioxus::prelude::*;
+/// use radiors::dioxus::{Group, Radio, ThemeProvider};
+/// use radiors::{ColorScheme, Theme};
+///
+/// #[component]
+/// pub fn App() -> Element {
+///     rsx! {
+///         ThemeProvider {
+///             theme: Theme::default(),
+///             color_scheme: ColorScheme::Dark,
+///             Group::<&str> {
+///                 default_selected: "Option1",
+///                 Radio { value: "Option1", label: "Option 1" }
+///                 Radio { value: "Option2", label: "Option 2" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - The provider renders no element of its own. A `Radio` used outside of a `Group` reads the
+///   variables from any ancestor, e.g. one styled with `Theme::to_style`.
+/// - With `ColorScheme::System`, the light theme is rendered until the preference is read in the
+///   browser, and switching the system preference updates the groups right away.
+#[component]
+pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
+    let ThemeProviderProps {
+        theme,
+        dark_theme,
+        color_scheme,
+        children,
+    } = props;
+    let prefers_dark = use_signal(|| false);
+    let mut listener = use_signal(|| None::<ColorSchemeListener>);
+    use_effect(move || {
+        let on_change = Runtime::wrap_closure(move |dark: bool| {
+            let mut prefers_dark = prefers_dark;
+            prefers_dark.set(dark);
+        });
+        listener.set(ColorSchemeListener::new(on_change));
+    });
+    let resolved = use_memo(move || {
+        if color_scheme().is_dark(prefers_dark()) {
+            dark_theme()
+        } else {
+            theme()
+        }
+    });
+    use_context_provider(|| ReadOnlySignal::from(resolved));
+    rsx! {
+        {children}
+    }
+}
@@ -1,7 +1,178 @@
 #![allow(unused)]
 
-use crate::common::{Orientation, Size, Type, HIDDEN_INPUT_STYLE};
-use leptos::{ev::MouseEvent, prelude::*};
+use crate::common::{
+    class_list, effective_variant, inline_style, radio_class, segment_highlight_style, size_style,
+    themed_radio_style, type_style, variant_style, Align, ColorScheme, ColorSchemeListener,
+    FormResetListener, GroupLayout, IdCounter, Justify, LazyId, Orientation, RadioGroupState,
+    RadioOption, RadioValue, Size, Theme, Type, Variant, WidthListener, CHECK_MARK_CLASS,
+    CHECK_MARK_STYLE, DESCRIPTION_CLASS, DESCRIPTION_STYLE, HIDDEN_INPUT_CLASS, HIDDEN_INPUT_STYLE,
+    SEGMENT_HIGHLIGHT_CLASS,
+};
+use leptos::{
+    ev::{KeyboardEvent, MouseEvent},
+    html::Div,
+    prelude::*,
+    tachys::html::attribute::{
+        custom::{custom_attribute, CustomAttr},
+        AttributeValue,
+    },
+};
+
+/// Maps an empty string prop to `None` so that the attribute is omitted.
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+/// A `class` attribute that is omitted for `None`, where `class=` would still render `class=""`.
+fn class_attr<V: AttributeValue>(value: V) -> CustomAttr<&'static str, V> {
+    custom_attribute("class", value)
+}
+
+/// A `style` attribute rendered as given, where `style=` would append another `;`.
+fn style_attr<V: AttributeValue>(value: V) -> CustomAttr<&'static str, V> {
+    custom_attribute("style", value)
+}
+
+/// Selection state shared by a `Group` with its descendant `Radio` components.
+///
+/// The `Group` provides this through Leptos context so that every `Radio`
+/// rendered inside it, directly or nested in other elements, can read the
+/// currently selected value and report clicks back to the group. Each `Radio`
+/// registers itself in `state` when it mounts and unregisters when it is cleaned up.
+///
+/// The context is looked up by type, so a `Radio` only joins a `Group` with the same value type.
+struct GroupContext<T: Send + Sync + 'static> {
+    state: RwSignal<RadioGroupState<T>>,
+    options: Signal<Vec<RadioOption<T>>>,
+    controlled: StoredValue<bool>,
+    bind: Option<RwSignal<T>>,
+    onchange: Callback<(T,), ()>,
+    name: Signal<String>,
+    form: MaybeProp<String>,
+    variant: Variant,
+}
+
+impl<T: Send + Sync> Clone for GroupContext<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync> Copy for GroupContext<T> {}
+
+impl<T: RadioValue + Send + Sync> GroupContext<T> {
+    /// Registers a `Radio` while it is set up, and unregisters it when it is cleaned up.
+    fn join(&self, value: StoredValue<T>, disabled: bool) {
+        let state = self.state;
+        untrack(|| {
+            let registered = state.with(|state| {
+                value.with_value(|value| {
+                    state.options().contains(value) && state.is_disabled(value) == disabled
+                })
+            });
+            if !registered {
+                state.update(|state| state.register(value.get_value(), disabled));
+            }
+        });
+        // Rendering `options` to HTML drops each radio right after it is rendered, so the
+        // group keeps the ones it still lists, which it registered itself.
+        let options = self.options;
+        on_cleanup(move || {
+            let listed = value.try_with_value(|value| {
+                options
+                    .try_with_untracked(|options| {
+                        options.iter().any(|option| option.value == *value)
+                    })
+                    .unwrap_or(false)
+            });
+            if listed == Some(false) {
+                state.try_update(|state| value.with_value(|value| state.unregister(value)));
+            }
+        });
+    }
+
+    /// Applies a transition to the group state, emitting `onchange` when it picks a new value.
+    fn transition(&self, f: impl FnOnce(&mut RadioGroupState<T>) -> bool) {
+        if self.controlled.get_value() {
+            let Some(mut state) = self.state.try_get_untracked() else {
+                return;
+            };
+            let changed = f(&mut state);
+            if let Some(focused) = state.focused() {
+                if self
+                    .state
+                    .with_untracked(|current| !current.is_focused(focused))
+                {
+                    self.state.update(|current| {
+                        current.focus(focused);
+                    });
+                }
+            }
+            if changed {
+                if let Some(value) = state.selected() {
+                    if let Some(bind) = self.bind {
+                        bind.set(value.clone());
+                    }
+                    self.onchange.run((value.clone(),));
+                }
+            }
+            return;
+        }
+        let changed = self.state.try_update(f).unwrap_or(false);
+        if changed {
+            if let Some(value) = self.state.with_untracked(|state| state.selected().cloned()) {
+                self.onchange.run((value,));
+            }
+        }
+    }
+}
+
+/// The selected value of a controlled `Group`, given as a value or a signal.
+///
+/// Converts from `T`, `Option<T>` and the signals of either. Unlike `MaybeProp<T>`, it has
+/// no conversion from `&str` to `String`, so a string literal leaves the value type of the
+/// group inferable.
+pub struct Selection<T: Send + Sync + 'static>(Signal<Option<T>>);
+
+impl<T: Send + Sync> Clone for Selection<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync> Copy for Selection<T> {}
+
+impl<T: RadioValue + Send + Sync> From<T> for Selection<T> {
+    fn from(value: T) -> Self {
+        Self(Signal::stored(Some(value)))
+    }
+}
+
+impl<T: RadioValue + Send + Sync> From<Option<T>> for Selection<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(Signal::stored(value))
+    }
+}
+
+macro_rules! selection_from_signals {
+    ($($signal:ident),*) => {
+        $(
+            impl<T: RadioValue + Send + Sync> From<$signal<T>> for Selection<T> {
+                fn from(signal: $signal<T>) -> Self {
+                    Self(Signal::derive(move || Some(signal.get())))
+                }
+            }
+
+            impl<T: RadioValue + Send + Sync> From<$signal<Option<T>>> for Selection<T> {
+                fn from(signal: $signal<Option<T>>) -> Self {
+                    Self(signal.into())
+                }
+            }
+        )*
+    };
+}
+
+selection_from_signals!(ReadSignal, RwSignal, Memo, Signal);
 
 /// Group Component
 ///
@@ -11,15 +182,32 @@ use leptos::{ev::MouseEvent, prelude::*};
 ///
 /// # Properties
 ///
-/// - **selected**: A string representing the currently selected value in the group. The default value is an empty string.
-/// - **onchange**: A callback triggered whenever the selection changes. The callback receives the selected value (of type `String`).
-/// - **orientation**: Defines the layout of the radio buttons within the group. It can be either `Horizontal` or `Vertical` (default: `Horizontal`).
-/// - **style**: Inline styles applied to the group container (`&'static str`). Default: `""`.
-/// - **class**: CSS class for the group container (`&'static str`). Default: `""`.
+/// - **selected**: The selected value of a controlled group (`Selection<T>`), as a value, an `Option` or a signal of either. The default is an uncontrolled group.
+/// - **default_selected**: The initially selected value of an uncontrolled group (`T`). The default is no selection.
+/// - **bind**: A signal the group reads its selection from and writes new selections into (`RwSignal<T>`). The default is no signal.
+/// - **onchange**: A callback triggered whenever the selection changes. The callback receives the selected value (of type `T`).
+/// - **orientation**: Defines the layout of the radio buttons within the group. It can be `Horizontal`, `Vertical`, `Grid` or `Responsive` (default: `Vertical`).
+/// - **gap**: Spacing between the radio buttons (`Option<Size>`). Default: `None`.
+/// - **align**, **justify**: Cross-axis alignment and main-axis distribution (`Option<Align>`, `Option<Justify>`). Default: `None`.
+/// - **wrap**: Whether the radio buttons wrap onto new lines. The default value is `false`.
+/// - **style**: Inline styles applied to the group container (`MaybeProp<String>`). Default: `""`.
+/// - **class**: CSS class for the group container (`MaybeProp<String>`). Default: `""`.
+/// - **aria_label**, **aria_labelledby**, **aria_describedby**: Accessible name and description of the group (`MaybeProp<String>`). Default: `""`.
+/// - **required**: Whether a selection is required. The default value is `false`.
+/// - **name**: Name shared by the group's inputs (`MaybeProp<String>`). Default: a generated unique name.
+/// - **form**: ID of the `<form>` the inputs belong to (`MaybeProp<String>`). Default: `""`.
+/// - **options**: Options rendered as `Radio` components before the children (`Signal<Vec<RadioOption<T>>>`). Default: empty.
+/// - **size**, **r#type**, **variant**, **check_mark**: Size, styling type, look and check mark of the radio buttons
+///   rendered from `options` (`Size`, `Type`, `Variant`, `bool`).
+/// - **radio_style**, **radio_class**, **selected_style**, **selected_class**, **disabled_style**, **disabled_class**,
+///   **hover_style**, **hover_class**, **description_style**, **description_class**: Styles and classes of the radio buttons
+///   rendered from `options` (`MaybeProp<String>`). Default: `""`.
 /// - **children**: Child `Radio` components nested inside the `Group`. These components will be rendered as part of the group.
 ///
 /// # Features
 /// - Supports both horizontal and vertical orientations for the radio buttons.
+/// - Typed gap, alignment, justification and wrapping that compose with any orientation.
+/// - A segmented control look through `Variant::Segmented`.
 /// - Customizable inline styles and CSS classes.
 /// - Callback mechanism for reacting to selection changes.
 ///
@@ -33,13 +221,12 @@ use leptos::{ev::MouseEvent, prelude::*};
 ///
 /// #[component]
 /// pub fn App() -> impl IntoView {
-///     let selected = signal(String::new());
-///     let onchange = Callback::from(move |value: String| {
+///     let onchange = Callback::from(move |value: &'static str| {
 ///         log!("Selected value changed to: {}", value);
 ///     });
 ///
 ///     view! {
-///         <Group selected={selected.0.get()} onchange={onchange}>
+///         <Group default_selected="option1" onchange={onchange}>
 ///             <Radio value="option1" label="Option 1" />
 ///             <Radio value="option2" label="Option 2" />
 ///             <Radio value="option3" label="Option 3" />
@@ -48,6 +235,72 @@ use leptos::{ev::MouseEvent, prelude::*};
 /// }
 /// ```
 ///
+/// ## Typed Values
+/// `Group` and `Radio` are generic over their value type. Any `Clone + PartialEq + Display`
+/// type that is `Send + Sync`, such as an enum, can be bound directly:
+///
+/// ```rust
+/// use std::fmt;
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, Radio};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Plan {
+///     Basic,
+///     Pro,
+/// }
+///
+/// impl fmt::Display for Plan {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str(match self {
+///             Plan::Basic => "basic",
+///             Plan::Pro => "pro",
+///         })
+///     }
+/// }
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let plan = RwSignal::new(Plan::Basic);
+///
+///     view! {
+///         <Group bind=plan>
+///             <Radio value={Plan::Basic} label="Basic" />
+///             <Radio value={Plan::Pro} label="Pro" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
+/// ## Options from Data
+/// Groups loaded at runtime can be rendered from a collection of `RadioOption`s instead of
+/// hand-written `Radio` children. Passing a signal keeps the rendered options in sync:
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::Group;
+/// use radiors::{RadioOption, Size, Type};
+///
+/// #[component]
+/// pub fn Shipping(methods: Vec<(u32, String, bool)>) -> impl IntoView {
+///     let options = RwSignal::new(
+///         methods
+///             .into_iter()
+///             .map(|(id, name, available)| RadioOption::new(id, name).with_disabled(!available))
+///             .collect::<Vec<_>>(),
+///     );
+///
+///     view! {
+///         <Group
+///             options=options
+///             onchange=Callback::from(move |id: u32| leptos::logging::log!("{id}"))
+///             size=Size::Medium
+///             r#type=Type::Primary
+///         />
+///     }
+/// }
+/// ```
+///
 /// ## Group with Vertical Orientation
 /// ```rust
 /// use leptos::prelude::*;
@@ -57,13 +310,12 @@ use leptos::{ev::MouseEvent, prelude::*};
 ///
 /// #[component]
 /// pub fn App() -> impl IntoView {
-///     let selected = signal(String::new());
-///     let onchange = Callback::from(move |value: String| {
+///     let onchange = Callback::from(move |value: &'static str| {
 ///         log!("Selected value changed to: {}", value);
 ///     });
 ///
 ///     view! {
-///         <Group selected={selected.0.get()} onchange={onchange} orientation={Orientation::Vertical}>
+///         <Group default_selected="option1" onchange={onchange} orientation={Orientation::Vertical}>
 ///             <Radio value="option1" label="Option 1" />
 ///             <Radio value="option2" label="Option 2" />
 ///             <Radio value="option3" label="Option 3" />
@@ -72,6 +324,69 @@ use leptos::{ev::MouseEvent, prelude::*};
 /// }
 /// ```
 ///
+/// ## Group with Grid Layout
+/// A 3-column plan picker that collapses to a single column on narrow containers:
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, Radio};
+/// use radiors::Orientation;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Group
+///             default_selected="basic"
+///             orientation=Orientation::responsive([
+///                 (0, Orientation::Vertical),
+///                 (640, Orientation::grid(3)),
+///             ])
+///         >
+///             <Radio value="basic" label="Basic" />
+///             <Radio value="pro" label="Pro" />
+///             <Radio value="enterprise" label="Enterprise" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
+/// ## Wrapping Layout
+/// A horizontal group that wraps onto new rows instead of overflowing narrow screens:
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, Radio};
+/// use radiors::{Align, Size};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Group default_selected="red" gap=Size::Small align=Align::Center wrap=true>
+///             <Radio value="red" label="Red" />
+///             <Radio value="green" label="Green" />
+///             <Radio value="blue" label="Blue" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
+/// ## Segmented Control
+/// A compact row of joined segments with a highlight that slides to the selection:
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, Radio};
+/// use radiors::Variant;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Group default_selected="week" variant=Variant::Segmented>
+///             <Radio value="day" label="Day" />
+///             <Radio value="week" label="Week" />
+///             <Radio value="month" label="Month" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
 /// ## Group with Custom Styles and Class
 /// ```rust
 /// use leptos::prelude::*;
@@ -80,14 +395,13 @@ use leptos::{ev::MouseEvent, prelude::*};
 ///
 /// #[component]
 /// pub fn App() -> impl IntoView {
-///     let selected = signal(String::new());
-///     let onchange = Callback::from(move |value: String| {
+///     let onchange = Callback::from(move |value: &'static str| {
 ///         log!("Selected value changed to: {}", value);
 ///     });
 ///
 ///     view! {
 ///         <Group
-///             selected={selected.0.get()}
+///             default_selected="option1"
 ///             onchange={onchange}
 ///             style="border: 1px solid black; padding: 10px;"
 ///             class="radio-group"
@@ -101,93 +415,420 @@ use leptos::{ev::MouseEvent, prelude::*};
 /// ```
 ///
 /// # Behavior
-/// - The `selected` property tracks the value of the selected radio button.
-/// - The `onchange` callback is triggered whenever the selected value changes.
-/// - The `orientation` property determines the layout of the radio buttons. By default, it is horizontal, but it can be set to vertical.
+/// - Clicking a `Radio` inside the group triggers the `onchange` callback when the value changes.
+/// - `selected`, `default_selected` and `bind` follow the selection rules of the [`common`](crate::common) module.
+/// - Child `Radio` components read the selection from context, so they may be wrapped in other elements.
+/// - The `orientation` property determines the layout of the radio buttons. By default, it is vertical, but it can be set to horizontal or a grid.
+/// - A responsive orientation observes the width of the container and applies the matching layout.
+/// - `gap`, `align`, `justify` and `wrap` are applied on top of the resolved orientation.
+/// - Inside a `ThemeProvider`, the container carries the theme as `--radiors-*` CSS variables.
 /// - Child `Radio` components are rendered as part of the `Group`. Each `Radio` component should have a unique `value` to distinguish between them.
 /// - Custom inline styles and CSS classes can be used for detailed customization of the group's appearance.
+/// - The inputs share the group's `name`, so the selection is submitted with its `<form>`, and resetting the form resets the group.
+/// - The container is rendered with `role="radiogroup"` and each `Radio` with `role="radio"` and `aria-checked`.
+/// - Only one radio button is in the tab order at a time: the selected one, or the first enabled one.
+/// - Arrow keys select the next or previous enabled radio button, wrapping at the ends, while `Home`
+///   and `End` jump to the first and last enabled one. `Space` selects the focused radio button.
 ///
 /// # Notes
-/// - The `selected` value should match one of the `Radio` component values in the group, or it will default to an empty string.
-/// - The `onchange` callback provides a way to react to user selection. It passes the new selected value as a `String`.
+/// - The `selected` value should match one of the `Radio` component values in the group, or nothing is selected.
+/// - The `onchange` callback provides a way to react to user selection. It passes the new selected value.
+/// - A `Radio` only joins a `Group` of the same value type, e.g. `&'static str` literals do not join a `Group`
+///   whose `selected` value is a `String`.
 /// - The `orientation` property allows for switching between a horizontal or vertical layout for the radio buttons.
 /// - Custom inline styles and classes allow for further customization of the group’s appearance and behavior.
+/// - Text properties such as `style`, `class`, `aria_label` and `name` accept string literals, owned `String`s and signals.
 #[component]
-pub fn Group(
-    /// Selected value in the group.
+pub fn Group<T: RadioValue + Send + Sync>(
+    /// Selected value of a controlled group, as a value, an `Option` or a signal of either.
+    ///
+    /// See the [`common`](crate::common) module for when a group is controlled.
+    #[prop(into, optional)]
+    selected: Option<Selection<T>>,
+
+    /// Initially selected value of an uncontrolled group.
     ///
-    /// This represents the value that is currently selected within the group of
-    /// radio buttons. The default value is an empty string.
-    #[prop(default = String::new())]
-    selected: String,
+    /// The default is no selection.
+    #[prop(optional)]
+    default_selected: Option<T>,
+
+    /// Signal bound to the selection of the group.
+    ///
+    /// Takes precedence over `selected` and `default_selected`.
+    #[prop(optional)]
+    bind: Option<RwSignal<T>>,
 
     /// Callback for when the selection changes.
     ///
     /// This callback is triggered whenever the selection changes. It passes the
-    /// selected value (of type `String`) to the callback function. This allows
+    /// selected value (of type `T`) to the callback function. This allows
     /// the parent component to react to changes in selection.
-    #[prop(default = Callback::from(|value: String| {}))]
-    onchange: Callback<(String,), ()>,
+    #[prop(default = Callback::from(|value: T| {}))]
+    onchange: Callback<(T,), ()>,
 
-    /// Orientation of the group (horizontal or vertical).
+    /// Orientation of the group (horizontal, vertical, grid or responsive).
     ///
     /// Specifies the layout of the radio buttons within the group. The `Orientation`
-    /// enum allows for either `Horizontal` or `Vertical` layouts. The default is `Horizontal`.
-    #[prop(default = Orientation::Horizontal)]
+    /// enum allows for `Horizontal`, `Vertical` and `Grid` layouts, or a `Responsive` one
+    /// switching between them at container widths. The default is `Vertical`.
+    #[prop(optional)]
     orientation: Orientation,
 
+    /// Spacing between the radio buttons, on the theme's spacing scale.
+    ///
+    /// Overrides the gap of the orientation, which is `16px` for flex layouts and the gap of
+    /// a `Grid`. The default is `None`.
+    #[prop(optional)]
+    gap: Option<Size>,
+
+    /// Cross-axis alignment of the radio buttons.
+    ///
+    /// Rendered as `align-items`. The default is `None`, which keeps the browser default.
+    #[prop(optional)]
+    align: Option<Align>,
+
+    /// Main-axis distribution of the radio buttons.
+    ///
+    /// Rendered as `justify-content`. The default is `None`, which keeps the browser default.
+    #[prop(optional)]
+    justify: Option<Justify>,
+
+    /// Whether the radio buttons of a horizontal or vertical group wrap onto new lines.
+    ///
+    /// Lets horizontal groups flow onto several rows on narrow screens instead of
+    /// overflowing. The default is `false`.
+    #[prop(optional)]
+    wrap: bool,
+
     /// Custom inline styles.
     ///
     /// This applies custom inline styles to the group container. It is a string
     /// value that will be included in the `style` attribute of the container.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: MaybeProp<String>,
 
     /// Custom CSS class.
     ///
     /// This applies a custom CSS class to the group container. It is a string value
     /// that will be added to the `class` attribute of the container. Defaults to an
     /// empty string.
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: MaybeProp<String>,
+
+    /// Accessible name of the group.
+    ///
+    /// Rendered as `aria-label` on the group container. Prefer `aria_labelledby` when the
+    /// group has a visible heading. Omitted when empty.
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+
+    /// ID of the element that labels the group.
+    ///
+    /// Rendered as `aria-labelledby` on the group container, e.g. the `id` of a visible
+    /// heading. Omitted when empty.
+    #[prop(into, optional)]
+    aria_labelledby: MaybeProp<String>,
+
+    /// ID of the element that describes the group.
+    ///
+    /// Rendered as `aria-describedby` on the group container. Omitted when empty.
+    #[prop(into, optional)]
+    aria_describedby: MaybeProp<String>,
+
+    /// Whether a selection is required.
+    ///
+    /// Exposes `aria-required` on the group and marks every child input as `required`.
+    /// Defaults to `false`.
+    #[prop(default = false)]
+    required: bool,
+
+    /// Name shared by the `<input>` elements of the group.
+    ///
+    /// Used as the field name when the group is submitted as part of a `<form>`. When
+    /// empty, a unique name is generated so that groups never share a browser radio group.
+    #[prop(into, optional)]
+    name: MaybeProp<String>,
+
+    /// ID of the `<form>` the group's inputs belong to.
+    ///
+    /// Only needed when the group is rendered outside of the form element. Omitted when empty.
+    #[prop(into, optional)]
+    form: MaybeProp<String>,
+
+    /// Options rendered as `Radio` components.
+    ///
+    /// Lets the group be built from a collection, such as records loaded at runtime. One
+    /// `Radio` is rendered per option, before the children, and the list updates when a
+    /// signal passed here changes. Defaults to no options.
+    #[prop(into, optional)]
+    options: Signal<Vec<RadioOption<T>>>,
+
+    /// Size of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to `Size::XSmall`.
+    #[prop(default = Size::XSmall)]
+    size: Size,
+
+    /// Styling type of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to `Type::None`.
+    #[prop(default = Type::None)]
+    r#type: Type,
+
+    /// Look of the radio buttons rendered from `options` and of child `Radio`
+    /// components that keep `Variant::Default`.
+    ///
+    /// `Variant::Segmented` renders the group as a segmented control with a sliding
+    /// highlight. Defaults to `Variant::Default`.
+    #[prop(optional)]
+    variant: Variant,
+
+    /// Whether the selected card rendered from `options` shows a check mark.
+    ///
+    /// Only applies to `Variant::Card`. Defaults to `false`.
+    #[prop(optional)]
+    check_mark: bool,
+
+    /// Inline styles for the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    radio_style: MaybeProp<String>,
+
+    /// CSS class for the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    radio_class: MaybeProp<String>,
+
+    /// Inline styles applied to the selected radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    selected_style: MaybeProp<String>,
+
+    /// CSS class applied to the selected radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    selected_class: MaybeProp<String>,
+
+    /// Inline styles applied to disabled radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    disabled_style: MaybeProp<String>,
+
+    /// CSS class applied to disabled radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    disabled_class: MaybeProp<String>,
+
+    /// Inline styles applied to the hovered radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    hover_style: MaybeProp<String>,
+
+    /// CSS class applied to the hovered radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    hover_class: MaybeProp<String>,
+
+    /// Inline styles for the descriptions of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    description_style: MaybeProp<String>,
+
+    /// CSS class for the descriptions of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    description_class: MaybeProp<String>,
 
     /// Child `Radio` components.
     ///
     /// These are the `Radio` components nested inside the `Group` component.
-    /// They will be rendered as part of the group. This is typically used to pass
-    /// a fragment of children elements to be displayed inside the group.
-    children: ChildrenFragment,
+    /// They will be rendered as part of the group and pick up the group's
+    /// selection state through context, so they may be nested at any depth.
+    /// Optional when the group is rendered from `options`.
+    #[prop(optional)]
+    children: Option<Children>,
 ) -> impl IntoView {
-    let (selected, set_selected) = signal(selected);
+    let selected = match bind {
+        Some(bind) => Some(Signal::derive(move || Some(bind.get()))),
+        None => selected.map(|selected| selected.0),
+    };
+    let mut state = RadioGroupState::default().with_required(required);
+    let initial = selected.and_then(|selected| selected.get_untracked());
+    let controlled = initial.is_some();
+    if let Some(selected) = initial.or(default_selected) {
+        state = state.with_selected(selected);
+    }
+    // The `options` are known before their radios register, which lets the first render,
+    // e.g. on the server, already place the segmented highlight.
+    options.with_untracked(|options| {
+        for option in options {
+            state.register(option.value.clone(), option.disabled);
+        }
+    });
+    let group = GroupContext {
+        state: RwSignal::new(state),
+        options,
+        controlled: StoredValue::new(controlled),
+        bind,
+        onchange,
+        name: {
+            let generated_name = StoredValue::new(LazyId::group_name(
+                use_context::<IdCounter>().unwrap_or_else(IdCounter::thread),
+            ));
+            if non_empty(name.get_untracked()).is_none() {
+                // Take the name before the radios take their description IDs, in the same
+                // order as the other adapters.
+                generated_name.with_value(|name| {
+                    name.get();
+                });
+            }
+            Signal::derive(move || {
+                non_empty(name.get())
+                    .unwrap_or_else(|| generated_name.with_value(|name| name.get().to_string()))
+            })
+        },
+        form,
+        variant,
+    };
+    provide_context(group);
+    if let Some(selected) = selected {
+        // Once `selected` has held a value, the group stays controlled, so setting it back to
+        // `None` clears the selection.
+        Effect::new(move |_| {
+            let selected = selected.get();
+            if selected.is_some() {
+                group.controlled.set_value(true);
+            }
+            if group.controlled.get_value()
+                && group
+                    .state
+                    .with_untracked(|state| state.selected() != selected.as_ref())
+            {
+                group.state.update(|state| state.set_selected(selected));
+            }
+        });
+    }
+    let theme = use_context::<Signal<Theme>>();
+
+    let node = NodeRef::<Div>::new();
+    // Runs again when the group moves to another form.
+    Effect::new(move |_| {
+        let form = form.get().unwrap_or_default();
+        node.get().and_then(|container| {
+            FormResetListener::new(&container, &form, move || {
+                group.transition(|state| state.reset())
+            })
+        })
+    });
+    let width = RwSignal::new(None::<u32>);
+    let responsive = orientation.is_responsive();
+    Effect::new(move |_| {
+        node.get().filter(|_| responsive).and_then(|container| {
+            WidthListener::new(&container, move |value| width.set(Some(value)))
+        })
+    });
+    let orientation = StoredValue::new(orientation);
+    let gap = StoredValue::new(gap);
+    let group_type = StoredValue::new(r#type.clone());
+    let layout = move |render: fn(&GroupLayout) -> String| {
+        orientation.with_value(|orientation| {
+            gap.with_value(|gap| {
+                render(&GroupLayout {
+                    variant,
+                    r#type: &group_type.read_value(),
+                    orientation: orientation.resolve(width.get()),
+                    gap: gap.as_ref(),
+                    align,
+                    justify,
+                    wrap,
+                })
+            })
+        })
+    };
+
+    let highlight = move || {
+        (variant == Variant::Segmented)
+            .then(|| group.state.with(segment_highlight_style))
+            .flatten()
+    };
+    let r#type = match variant {
+        Variant::Segmented => Type::None,
+        _ => r#type,
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let mut handled = false;
+        group.transition(|state| {
+            let changed = state.handle_key(&e.key());
+            handled = changed.is_some();
+            changed.unwrap_or(false)
+        });
+        if handled {
+            e.prevent_default();
+        }
+    };
 
     view! {
         <div
-            class=class
-            style=format!(
-                "{} {}",
-                orientation.to_style(),
-                style
-            )
+            node_ref=node
+            role="radiogroup"
+            aria-label=move || non_empty(aria_label.get())
+            aria-labelledby=move || non_empty(aria_labelledby.get())
+            aria-describedby=move || non_empty(aria_describedby.get())
+            aria-required=required.then_some("true")
+            {..class_attr(move || class_list([
+                layout(|layout| layout.to_class()),
+                class.get().unwrap_or_default(),
+            ]))}
+            {..style_attr(move || inline_style([
+                theme.map(|theme| theme.get().to_style()).unwrap_or_default(),
+                layout(|layout| layout.to_style()),
+                style.get().unwrap_or_default(),
+            ]))}
+            on:keydown=onkeydown
         >
-            {children().nodes.into_iter().map(|child| {
-                // TODO:
-                // Extract props from AnyView
-                // let props = child;
-                // let is_selected = props.value == selected.get();
-                let on_click = {
-                    let onchange = onchange.clone();
-                    // let value = props.value.clone();
-                    // move || {
-                    //     set_selected.set(value.clone());
-                    //     onchange.emit(value.clone());
-                    // }
-                };
-
-                // TODO:
-                // Update selected and on_click
-                child
-            }).collect::<Vec<_>>()}
+            {move || highlight().map(|style| view! {
+                <span aria-hidden="true" {..style_attr(style)} {..class_attr(class_list([SEGMENT_HIGHLIGHT_CLASS]))}></span>
+            })}
+            <For
+                each=move || options.get()
+                key=|option| option.value.to_value()
+                let:option
+            >
+                <Radio
+                    value=option.value
+                    label=option.label
+                    src=option.src
+                    description=option.description
+                    disabled=option.disabled
+                    size=size.clone()
+                    r#type=r#type.clone()
+                    variant=variant
+                    check_mark=check_mark
+                    style=radio_style
+                    class=radio_class
+                    selected_style=selected_style
+                    selected_class=selected_class
+                    disabled_style=disabled_style
+                    disabled_class=disabled_class
+                    hover_style=hover_style
+                    hover_class=hover_class
+                    description_style=description_style
+                    description_class=description_class
+                />
+            </For>
+            {children.map(|children| children())}
         </div>
     }
 }
@@ -202,10 +843,14 @@ pub fn Group(
 ///
 /// - **label**: A string label associated with the radio button. It provides a descriptive text next to the radio button.
 ///   The default is an empty string.
-/// - **value**: A string value representing the value of the radio button when selected. It is used to identify the selected option.
-///   The default is an empty string.
+/// - **value**: The value of the radio button (`T`) when selected. It is used to identify the selected option.
+///   This property is required.
 /// - **src**: An optional image source to display alongside the radio button. If not specified, no image is shown.
 ///   The default is an empty string.
+/// - **description**: Secondary text rendered under the label. The default is an empty string.
+/// - **description_style**, **description_class**: Inline styles and CSS class of the description. The default is an empty string.
+/// - **leading**, **trailing**: Views rendered before and after the label (`ViewFn`). The default is no content.
+/// - **children**: Rich label content rendered after the `label` text. The default is no content.
 /// - **style**: Inline styles applied to the container element of the radio button. The default is an empty string.
 /// - **class**: CSS class applied to the container element. The default is an empty string.
 /// - **label_style**: Inline styles for the label element. The default is an empty string.
@@ -215,21 +860,31 @@ pub fn Group(
 /// - **size**: Defines the size of the radio button, based on the `Size` enum. Possible values include `XSmall`, `Small`, `Medium`, and `Large`.
 ///   The default is `Size::XSmall`.
 /// - **r#type**: Defines the type of the radio button. This is based on the `Type` enum and can define different behaviors or styles. The default is `Type::None`.
+/// - **variant**: The look of the radio button, based on the `Variant` enum. The default is `Variant::Default`.
+/// - **check_mark**: Whether a selected card shows a check mark. The default value is `false`.
 /// - **selected**: Whether the radio button is selected by default. The default value is `false`.
 /// - **disabled**: Whether the radio button is disabled, preventing user interaction. The default value is `false`.
+/// - **required**: Whether the underlying input is required. The default value is `false`.
+/// - **name**: Name of the underlying input. The default is an empty string, or the group's name inside a `Group`.
+/// - **form**: ID of the `<form>` the input belongs to. The default is an empty string.
 /// - **selected_style**: Inline styles applied when the radio button is selected. The default is an empty string.
 /// - **selected_class**: CSS class applied when the radio button is selected. The default is an empty string.
 /// - **disabled_style**: Inline styles applied when the radio button is disabled. The default is an empty string.
 /// - **disabled_class**: CSS class applied when the radio button is disabled. The default is an empty string.
 /// - **animation_style**: Inline styles applied for animations (e.g., hover effects). The default is an empty string.
 /// - **animation_class**: CSS class applied for animations. The default is an empty string.
+/// - **hover_style**, **hover_class**: Inline styles and CSS class applied while hovered. The default is an empty string.
+/// - **active_style**, **active_class**: Inline styles and CSS class applied while pressed. The default is an empty string.
+/// - **focus_style**, **focus_class**: Inline styles and CSS class applied while focused. The default is an empty string.
 /// - **input_style**: Inline styles applied to the hidden input element associated with the radio button. The default is `HIDDEN_INPUT_STYLE`.
 /// - **input_class**: CSS class applied to the hidden input element. The default is an empty string.
-/// - **on_click**: A callback triggered when the radio button is clicked. It passes the `value` of the radio button as a `String` to the callback function.
+/// - **on_click**: A callback triggered when the radio button is clicked. It passes the `value` of the radio button to the callback function.
 ///   The default is an empty callback.
 ///
 /// # Features
 /// - Supports custom labels, images, styles, and classes.
+/// - Rich content through the `leading` and `trailing` slots and children.
+/// - A card look for plan and product pickers through `Variant::Card`.
 /// - Provides support for multiple sizes of radio buttons.
 /// - Allows for radio buttons to be selected, disabled, or animated.
 /// - Customizable styles when selected or disabled.
@@ -269,6 +924,68 @@ pub fn Group(
 /// }
 /// ```
 ///
+/// ## Rich Content
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::Radio;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Radio
+///             value="express"
+///             leading=|| view! { <i class="icon-truck"></i> }
+///             trailing=|| view! { <strong>"$9.99"</strong> }
+///         >
+///             "Express " <span class="badge">"Fastest"</span>
+///         </Radio>
+///     }
+/// }
+/// ```
+///
+/// ## Card
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::Radio;
+/// use radiors::{Size, Variant};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Radio
+///             value="pro"
+///             label="Pro"
+///             description="For growing teams"
+///             trailing=|| view! { <strong>"$29/mo"</strong> }
+///             variant=Variant::Card
+///             size=Size::Large
+///             selected=true
+///             check_mark=true
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Hover, Active and Focus States
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Radio};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Radio
+///             value="option1"
+///             label="Option 1"
+///             animation_style="transition: background-color 0.2s;"
+///             hover_style="background-color: #f0f0f0;"
+///             active_class="pressed"
+///             focus_style="outline: 2px solid #007bff;"
+///         />
+///     }
+/// }
+/// ```
+///
 /// ## Disabled Radio Button
 /// ```rust
 /// use leptos::prelude::*;
@@ -287,85 +1004,154 @@ pub fn Group(
 /// }
 /// ```
 ///
+/// ## Reactive Labels and Classes
+/// Text properties accept owned strings and signals, and update in place when they change:
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Radio};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let (count, set_count) = signal(0);
+///
+///     view! {
+///         <Radio
+///             value="option1"
+///             label=Signal::derive(move || format!("Clicked {} times", count.get()))
+///             class=Signal::derive(move || format!("count-{}", count.get()))
+///             on_click=Callback::from(move |_: &'static str| *set_count.write() += 1)
+///         />
+///     }
+/// }
+/// ```
+///
 /// # Behavior
-/// - The `selected` property determines whether the radio button is selected by default. If set to `true`, the radio button is selected on render.
+/// - The `selected` property determines whether a standalone radio button is selected. Inside a `Group`, the group's selection takes precedence.
 /// - The `disabled` property disables the radio button, preventing any user interaction. It also changes the appearance of the radio button based
 ///    on the `disabled_style` and `disabled_class` properties.
 /// - The `on_click` callback is triggered when the radio button is clicked. It receives the `value` of the radio button as a parameter.
 /// - The `value` property is used to track which option is selected within a group of radio buttons.
+/// - Hovering, pressing and focusing the radio button add the `hover_*`, `active_*` and `focus_*` styles
+///   and classes after the other ones, so they take precedence. Disabled radio buttons ignore them.
+/// - A non-empty `description` is rendered with a generated `id` that the radio button's
+///   `aria-describedby` refers to, so assistive technology announces it after the label.
+/// - Clicks on `leading`, `trailing` and children content select the radio button like clicks on
+///   its label, and their text is part of the radio button's accessible name.
 ///
 /// # Notes
 /// - The radio button is often used as part of a `Group` of radio buttons, where only one option can be selected at a time.
 /// - Custom inline styles and CSS classes provide flexibility in the visual presentation of the radio button.
 /// - The `size`, `type`, and `selected` properties allow for a customizable user experience.
 /// - The `animation_style` and `animation_class` properties enable animations like hover or transition effects for enhanced interaction.
+/// - Text properties such as `label`, `src`, `style` and `class` are `MaybeProp<String>`, so they accept string literals,
+///   owned `String`s and signals such as `Signal::derive(..)`.
 #[component]
-pub fn Radio(
+pub fn Radio<T: RadioValue + Send + Sync>(
     /// Label for the radio button.
     ///
     /// This is the text label associated with the radio button. It is displayed
     /// next to the radio button to indicate its meaning. The default value is an
     /// empty string, meaning no label will be displayed unless specified.
-    #[prop(default = "")]
-    label: &'static str,
+    #[prop(into, optional)]
+    label: MaybeProp<String>,
 
     /// Value of the radio button.
     ///
     /// The value that this radio button represents when it is selected. It can
     /// be used to identify the selected option in a group of radio buttons.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    value: &'static str,
+    /// Its string form, given by `RadioValue::to_value`, is used as the `value`
+    /// attribute of the underlying `<input>`.
+    value: T,
 
     /// Image source (optional).
     ///
     /// An optional image that can be displayed alongside the radio button. If
     /// no image is provided, this field defaults to an empty string, meaning
     /// no image will be shown.
-    #[prop(default = "")]
-    src: &'static str,
+    #[prop(into, optional)]
+    src: MaybeProp<String>,
+
+    /// Description (optional).
+    ///
+    /// Secondary text rendered under the label, e.g. to explain the option in
+    /// more detail, and referenced by the radio button's `aria-describedby`.
+    /// Omitted when empty.
+    #[prop(into, optional)]
+    description: MaybeProp<String>,
+
+    /// Inline styles for the description.
+    ///
+    /// Applied after the built-in description styles. Defaults to an empty string.
+    #[prop(into, optional)]
+    description_style: MaybeProp<String>,
+
+    /// CSS class for the description.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    description_class: MaybeProp<String>,
+
+    /// Content rendered before the label (optional).
+    ///
+    /// A view such as an icon, e.g. `leading=|| view! { <i class="icon" /> }`.
+    #[prop(into, optional)]
+    leading: Option<ViewFn>,
+
+    /// Content rendered after the label (optional).
+    ///
+    /// A view such as a badge or a price, rendered before the description.
+    #[prop(into, optional)]
+    trailing: Option<ViewFn>,
+
+    /// Rich label content (optional).
+    ///
+    /// Rendered inside the label element after the `label` text, so formatted text
+    /// can replace or extend it.
+    #[prop(optional)]
+    children: Option<Children>,
 
     /// Inline styles for the container.
     ///
     /// These are the inline styles applied to the container element of the radio button.
     /// This allows custom styling of the radio button's wrapper element. Defaults to an empty string.
-    #[prop(default = "")]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: MaybeProp<String>,
 
     /// CSS class for the container.
     ///
     /// This applies a CSS class to the container element, allowing for custom styling.
     /// The default value is an empty string, meaning no class is applied unless specified.
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: MaybeProp<String>,
 
     /// Inline styles for the label.
     ///
     /// Inline styles that apply specifically to the label text of the radio button.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    label_style: &'static str,
+    #[prop(into, optional)]
+    label_style: MaybeProp<String>,
 
     /// CSS class for the label.
     ///
     /// The CSS class applied to the label element, allowing custom styling of the label.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    label_class: &'static str,
+    #[prop(into, optional)]
+    label_class: MaybeProp<String>,
 
     /// Inline styles for the image.
     ///
     /// These styles are applied specifically to the image element, if an image is used
     /// for the radio button. Defaults to an empty string.
-    #[prop(default = "")]
-    image_style: &'static str,
+    #[prop(into, optional)]
+    image_style: MaybeProp<String>,
 
     /// CSS class for the image.
     ///
     /// The CSS class applied to the image element, if an image is provided for the radio button.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    image_class: &'static str,
+    #[prop(into, optional)]
+    image_class: MaybeProp<String>,
 
     /// Size of the radio button.
     ///
@@ -381,10 +1167,24 @@ pub fn Radio(
     #[prop(default = Type::None)]
     r#type: Type,
 
+    /// Look of the radio button.
+    ///
+    /// `Variant::Card` renders a bordered card for plan and product pickers. The default
+    /// is `Variant::Default`.
+    #[prop(optional)]
+    variant: Variant,
+
+    /// Whether a selected card shows a check mark in its corner.
+    ///
+    /// Only applies to `Variant::Card`. The default value is `false`.
+    #[prop(optional)]
+    check_mark: bool,
+
     /// Whether the radio button is selected.
     ///
     /// This determines whether the radio button is selected by default. If set to `true`,
-    /// the radio button is selected when rendered. Defaults to `false`.
+    /// the radio button is selected when rendered. Ignored when the radio button is
+    /// rendered inside a `Group`, which manages selection itself. Defaults to `false`.
     #[prop(default = false)]
     selected: bool,
 
@@ -395,118 +1195,434 @@ pub fn Radio(
     #[prop(default = false)]
     disabled: bool,
 
+    /// Whether a selection is required.
+    ///
+    /// Marks the underlying input as `required`. Inside a `Group`, the group's `required`
+    /// property also applies. Defaults to `false`.
+    #[prop(default = false)]
+    required: bool,
+
+    /// Name of the underlying input.
+    ///
+    /// Used as the field name when submitted as part of a `<form>`. Inside a `Group`,
+    /// the group's name is used instead. Omitted when empty.
+    #[prop(into, optional)]
+    name: MaybeProp<String>,
+
+    /// ID of the `<form>` the underlying input belongs to.
+    ///
+    /// Inside a `Group`, defaults to the group's `form`. Omitted when empty.
+    #[prop(into, optional)]
+    form: MaybeProp<String>,
+
     /// Inline styles when selected.
     ///
     /// Custom inline styles that are applied when the radio button is selected. This allows
     /// styling changes to visually indicate that the radio button is in a selected state.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    selected_style: &'static str,
+    #[prop(into, optional)]
+    selected_style: MaybeProp<String>,
 
     /// CSS class when selected.
     ///
     /// The CSS class applied to the radio button when it is selected. This allows for
     /// styling changes when the radio button is in a selected state. Defaults to an empty string.
-    #[prop(default = "")]
-    selected_class: &'static str,
+    #[prop(into, optional)]
+    selected_class: MaybeProp<String>,
 
     /// Inline styles when disabled.
     ///
     /// Custom inline styles that are applied when the radio button is disabled. This allows
     /// for styling changes to visually indicate that the radio button is disabled.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    disabled_style: &'static str,
+    #[prop(into, optional)]
+    disabled_style: MaybeProp<String>,
 
     /// CSS class when disabled.
     ///
     /// The CSS class applied to the radio button when it is disabled. This allows for
     /// styling changes when the radio button is in a disabled state. Defaults to an empty string.
-    #[prop(default = "")]
-    disabled_class: &'static str,
+    #[prop(into, optional)]
+    disabled_class: MaybeProp<String>,
 
     /// Inline styles for animations.
     ///
     /// These inline styles are applied when the radio button has animations, such as a hover
     /// effect or transition. Defaults to an empty string.
-    #[prop(default = "")]
-    animation_style: &'static str,
+    #[prop(into, optional)]
+    animation_style: MaybeProp<String>,
 
     /// CSS class for animations.
     ///
     /// The CSS class applied to the radio button when animations are enabled. Defaults to an empty string.
-    #[prop(default = "")]
-    animation_class: &'static str,
+    #[prop(into, optional)]
+    animation_class: MaybeProp<String>,
+
+    /// Inline styles applied while the pointer is over the radio button.
+    ///
+    /// Not applied while the radio button is disabled. Defaults to an empty string.
+    #[prop(into, optional)]
+    hover_style: MaybeProp<String>,
+
+    /// CSS class applied while the pointer is over the radio button.
+    ///
+    /// Not applied while the radio button is disabled. Defaults to an empty string.
+    #[prop(into, optional)]
+    hover_class: MaybeProp<String>,
+
+    /// Inline styles applied while the radio button is being pressed.
+    ///
+    /// Not applied while the radio button is disabled. Defaults to an empty string.
+    #[prop(into, optional)]
+    active_style: MaybeProp<String>,
+
+    /// CSS class applied while the radio button is being pressed.
+    ///
+    /// Not applied while the radio button is disabled. Defaults to an empty string.
+    #[prop(into, optional)]
+    active_class: MaybeProp<String>,
+
+    /// Inline styles applied while the radio button has keyboard focus.
+    ///
+    /// Not applied while the radio button is disabled. Defaults to an empty string.
+    #[prop(into, optional)]
+    focus_style: MaybeProp<String>,
+
+    /// CSS class applied while the radio button has keyboard focus.
+    ///
+    /// Not applied while the radio button is disabled. Defaults to an empty string.
+    #[prop(into, optional)]
+    focus_class: MaybeProp<String>,
 
     /// Inline styles for the hidden input.
     ///
     /// Inline styles applied to the hidden input element associated with the radio button.
     /// This is useful for cases where the input element needs custom styling. Defaults to the
     /// constant value `HIDDEN_INPUT_STYLE`.
-    #[prop(default = HIDDEN_INPUT_STYLE)]
-    input_style: &'static str,
+    #[prop(into, default = HIDDEN_INPUT_STYLE.into())]
+    input_style: Signal<String>,
 
     /// CSS class for the hidden input.
     ///
     /// CSS class applied to the hidden input element associated with the radio button.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    input_class: &'static str,
+    /// Defaults to `radiors-radio__input` with the `stylesheet` feature, and to an empty string otherwise.
+    #[prop(into, default = HIDDEN_INPUT_CLASS.into())]
+    input_class: MaybeProp<String>,
 
     /// Callback for the click event.
     ///
     /// This callback is triggered when the radio button is clicked. It passes the `value`
-    /// of the radio button as a `String` to the callback function. This allows parent components
+    /// of the radio button to the callback function. This allows parent components
     /// to react to clicks on individual radio buttons.
-    #[prop(default = Callback::from(|value: String| {}))]
-    on_click: Callback<(String,), ()>,
+    #[prop(default = Callback::from(|value: T| {}))]
+    on_click: Callback<(T,), ()>,
 ) -> impl IntoView {
+    let group = use_context::<GroupContext<T>>();
+    let input_value = value.to_value();
+    let value = StoredValue::new(value);
+    if let Some(group) = group {
+        group.join(value, disabled);
+    }
+    let selected = move || {
+        group.map_or(selected, |group| {
+            group
+                .state
+                .with(|state| value.with_value(|value| state.is_selected(value)))
+        })
+    };
+    let name = move || match group {
+        Some(group) => Some(group.name.get()),
+        None => non_empty(name.get()),
+    };
+    let form = move || {
+        non_empty(form.get()).or_else(|| group.and_then(|group| non_empty(group.form.get())))
+    };
+    let required = move || {
+        required || group.is_some_and(|group| group.state.with(|state| state.is_required()))
+    };
+    let tab_stop = move || {
+        group.map_or(true, |group| {
+            group
+                .state
+                .with(|state| value.with_value(|value| state.is_tab_stop_while_registering(value)))
+        })
+    };
+
+    let node = NodeRef::<Div>::new();
+    let focused = Memo::new(move |_| {
+        group.is_some_and(|group| {
+            group
+                .state
+                .with(|state| value.with_value(|value| state.is_focused(value)))
+        })
+    });
+    Effect::new(move |_| {
+        if focused.get() {
+            if let Some(element) = node.get() {
+                let _ = element.focus();
+            }
+        }
+    });
+
+    let description_id = StoredValue::new(LazyId::description(
+        use_context::<IdCounter>().unwrap_or_else(IdCounter::thread),
+    ));
+
+    let hovered = RwSignal::new(false);
+    let active = RwSignal::new(false);
+    let has_focus = RwSignal::new(false);
+    let when = move |state: RwSignal<bool>, value: MaybeProp<String>| {
+        if state.get() && !disabled {
+            value.get()
+        } else {
+            None
+        }
+        .unwrap_or_default()
+    };
+
     let onclick = move |_: MouseEvent| {
         if !disabled {
-            on_click.run((value.to_string(),));
+            if let Some(group) = group {
+                group.transition(|state| value.with_value(|value| state.select(value)));
+            }
+            on_click.run((value.get_value(),));
         }
     };
 
+    let variant = effective_variant(
+        variant,
+        group.map_or(Variant::Default, |group| group.variant),
+    );
+    let builtin_class = {
+        let r#type = r#type.clone();
+        let size = size.clone();
+        move || radio_class(&variant, &r#type, &size, selected(), disabled)
+    };
+    let check_mark = move || variant == Variant::Card && check_mark && selected();
+
     view! {
         <div
-            class=format!(
-                "{} {} {}",
-                if selected { selected_class } else { "" },
-                if disabled { disabled_class } else { "" },
-                class
-            )
-            style=format!(
-                "{} {} {} {} {} {}",
-                if selected { selected_style } else { "" },
-                if disabled { disabled_style } else { "" },
-                style,
-                animation_style,
-                r#type.to_style(),
-                size.to_style()
-            )
+            node_ref=node
+            role="radio"
+            aria-checked=move || if selected() { "true" } else { "false" }
+            aria-disabled=disabled.then_some("true")
+            aria-describedby=move || non_empty(description.get()).map(|_| description_id.with_value(|id| id.get().to_string()))
+            tabindex=move || if tab_stop() && !disabled { "0" } else { "-1" }
+            {..class_attr(move || class_list([
+                builtin_class(),
+                if selected() { selected_class.get() } else { None }.unwrap_or_default(),
+                if disabled { disabled_class.get() } else { None }.unwrap_or_default(),
+                class.get().unwrap_or_default(),
+                animation_class.get().unwrap_or_default(),
+                when(hovered, hover_class),
+                when(has_focus, focus_class),
+                when(active, active_class),
+            ]))}
+            {..style_attr(move || inline_style([
+                themed_radio_style(selected(), has_focus.get() && !disabled),
+                variant_style(&variant, selected()),
+                if selected() { selected_style.get() } else { None }.unwrap_or_default(),
+                if disabled { disabled_style.get() } else { None }.unwrap_or_default(),
+                style.get().unwrap_or_default(),
+                animation_style.get().unwrap_or_default(),
+                type_style(&r#type),
+                size_style(&size),
+                when(hovered, hover_style),
+                when(has_focus, focus_style),
+                when(active, active_style),
+            ]))}
             on:click=onclick
+            on:mouseenter=move |_| hovered.set(true)
+            on:mouseleave=move |_| {
+                hovered.set(false);
+                active.set(false);
+            }
+            on:mousedown=move |_| active.set(true)
+            on:mouseup=move |_| active.set(false)
+            on:focus=move |_| has_focus.set(true)
+            on:blur=move |_| has_focus.set(false)
         >
             <input
                 r#type="radio"
-                value=value
+                name=name
+                form=form
+                value=input_value
                 checked=selected
                 disabled=disabled
-                style=input_style
-                class=input_class
+                required=required
+                tabindex="-1"
+                aria-hidden="true"
+                {..style_attr(move || inline_style([input_style.get()]))}
+                {..class_attr(move || class_list(input_class.get()))}
             />
-            {if !src.is_empty() {
-                Some(view! {
-                    <img
-                        src=src
-                        alt=label
-                        style=image_style
-                        class=image_class
-                    />
-                })
-            } else {
-                None
-            }}
-            <span style=label_style class=label_class>{label}</span>
+            {move || check_mark().then(|| view! {
+                <span
+                    aria-hidden="true"
+                    {..style_attr(inline_style([CHECK_MARK_STYLE]))}
+                    {..class_attr(class_list([CHECK_MARK_CLASS]))}
+                >
+                    "✓"
+                </span>
+            })}
+            {move || non_empty(src.get()).map(|src| view! {
+                <img
+                    src=src
+                    alt=move || label.get()
+                    {..style_attr(move || inline_style(image_style.get()))}
+                    {..class_attr(move || class_list(image_class.get()))}
+                />
+            })}
+            {leading.map(|leading| leading.run())}
+            <span
+                {..style_attr(move || inline_style(label_style.get()))}
+                {..class_attr(move || class_list(label_class.get()))}
+            >
+                {move || label.get()}
+                {children.map(|children| children())}
+            </span>
+            {trailing.map(|trailing| trailing.run())}
+            {move || non_empty(description.get()).map(|description| view! {
+                <span
+                    id=description_id.with_value(|id| id.get().to_string())
+                    {..style_attr(move || inline_style([
+                        DESCRIPTION_STYLE.to_string(),
+                        description_style.get().unwrap_or_default(),
+                    ]))}
+                    {..class_attr(move || class_list([
+                        DESCRIPTION_CLASS.to_string(),
+                        description_class.get().unwrap_or_default(),
+                    ]))}
+                >
+                    {description}
+                </span>
+            })}
         </div>
     }
 }
+
+/// ThemeProvider Component
+///
+/// Provides a `Theme` to its descendants through Leptos context. Every `Group` rendered
+/// below it emits the theme as `--radiors-*` CSS variables on its container, so
+/// changing the `theme` property restyles all of them at once.
+///
+/// # Properties
+/// - **theme**: The light `Theme` to provide (`Signal<Theme>`). Accepts a value or a signal.
+/// - **dark_theme**: The dark `Theme` to provide (`Signal<Theme>`). Default: `Theme::dark()`.
+/// - **color_scheme**: Whether to apply the light or the dark theme, or to follow the browser's
+///   `prefers-color-scheme` preference (`Signal<ColorScheme>`). Default: `ColorScheme::System`.
+/// - **children**: Content rendered inside the provider.
+///
+/// # Examples
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, Radio, ThemeProvider};
+/// use radiors::{Swatch, Theme, Type};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let (theme, _set_theme) = signal(Theme {
+///         primary: Swatch::new("#6d28d9", "#ffffff"),
+///         radius: "8px".into(),
+///         ..Theme::default()
+///     });
+///
+///     view! {
+///         <ThemeProvider theme=theme>
+///             <Group default_selected="Option1">
+///                 <Radio value="Option1" label="Option 1" r#type=Type::Primary />
+///                 <Radio value="Option2" label="Option 2" r#type=Type::Primary />
+///             </Group>
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+///
+/// ## Forced Dark Mode
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, Radio, ThemeProvider};
+/// use radiors::{ColorScheme, Theme};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <ThemeProvider theme=Theme::default() color_scheme=ColorScheme::Dark>
+///             <Group default_selected="Option1">
+///                 <Radio value="Option1" label="Option 1" />
+///                 <Radio value="Option2" label="Option 2" />
+///             </Group>
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - The provider renders no element of its own. A `Radio` used outside of a `Group` reads the
+///   variables from any ancestor, e.g. one styled with `Theme::to_style`.
+/// - With `ColorScheme::System`, the light theme is rendered until the preference is read in the
+///   browser, and switching the system preference updates the groups right away.
+#[component]
+pub fn ThemeProvider(
+    /// The light theme provided to every `Group` below this component.
+    #[prop(into)]
+    theme: Signal<Theme>,
+
+    /// The dark theme provided to every `Group` below this component.
+    ///
+    /// Defaults to `Theme::dark()`.
+    #[prop(into, default = Theme::dark().into())]
+    dark_theme: Signal<Theme>,
+
+    /// Which of `theme` and `dark_theme` is applied.
+    ///
+    /// Defaults to `ColorScheme::System`, which follows the browser's `prefers-color-scheme`.
+    #[prop(into, optional)]
+    color_scheme: Signal<ColorScheme>,
+
+    /// Content rendered inside the provider.
+    children: Children,
+) -> impl IntoView {
+    let prefers_dark = RwSignal::new(false);
+    Effect::new(move |_| ColorSchemeListener::new(move |dark| prefers_dark.set(dark)));
+    provide_context(Signal::derive(move || {
+        if color_scheme.get().is_dark(prefers_dark.get()) {
+            dark_theme.get()
+        } else {
+            theme.get()
+        }
+    }));
+    children()
+}
+
+/// IdScope Component
+///
+/// Counts the names and IDs generated for the groups and radio buttons below it from zero.
+/// Wrap the root of a server-rendered app in it, so that each render generates the same
+/// names and IDs as hydration does in the browser. Outside of a scope, they are counted
+/// per thread.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use radiors::leptos::{Group, IdScope, Radio};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <IdScope>
+///             <Group default_selected="Option1">
+///                 <Radio value="Option1" label="Option 1" description="The first option" />
+///                 <Radio value="Option2" label="Option 2" />
+///             </Group>
+///         </IdScope>
+///     }
+/// }
+/// ```
+#[component]
+pub fn IdScope(
+    /// Content rendered inside the scope.
+    children: Children,
+) -> impl IntoView {
+    provide_context(IdCounter::default());
+    children()
+}
@@ -16,4 +16,23 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::{Orientation, Size, Type};
+pub use common::{
+    Align, ColorScheme, Grid, Justify, Orientation, RadioGroupState, RadioOption, RadioOptions,
+    RadioValue, Size, Spacing, Swatch, Theme, Type, Variant, STYLESHEET,
+};
+
+/// Derives [`RadioOptions`](trait@RadioOptions) for an enum of unit variants.
+///
+/// Two variants cannot share a value:
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy, PartialEq, radiors::RadioOptions)]
+/// enum Plan {
+///     #[radio(value = "pro")]
+///     Pro,
+///     #[radio(value = "pro")]
+///     Team,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use radiors_derive::RadioOptions;
@@ -1,7 +1,20 @@
-use crate::common::{Orientation, Size, Type, HIDDEN_INPUT_STYLE};
+use crate::common::{
+    class_list, effective_variant, inline_style, radio_class, segment_highlight_style, size_style,
+    themed_radio_style, type_style, variant_style, Align, ColorScheme, ColorSchemeListener,
+    FormResetListener, GroupLayout, IdCounter, Justify, LazyId, Orientation, RadioGroupState,
+    RadioOption, RadioValue, Size, Theme, Type, Variant, WidthListener, CHECK_MARK_CLASS,
+    CHECK_MARK_STYLE, DESCRIPTION_CLASS, DESCRIPTION_STYLE, HIDDEN_INPUT_CLASS, HIDDEN_INPUT_STYLE,
+    SEGMENT_HIGHLIGHT_CLASS,
+};
+use std::marker::PhantomData;
 use std::rc::Rc;
 use yew::prelude::*;
 
+/// Maps an empty string prop to `None` so that the attribute is omitted.
+fn non_empty(value: &AttrValue) -> Option<AttrValue> {
+    (!value.is_empty()).then(|| value.clone())
+}
+
 /// Properties for configuring the `Group` component.
 ///
 /// The `Group` component allows you to create a group of radio buttons with customizable
@@ -10,43 +23,215 @@ use yew::prelude::*;
 ///
 /// It supports horizontal or vertical layouts, CSS customizations, and child components of type `Radio` only.
 #[derive(Properties, Clone, PartialEq)]
-pub struct GroupProps {
-    /// The selected value of the radio group.
+pub struct GroupProps<T: RadioValue = String> {
+    /// The selected value of a controlled group.
+    ///
+    /// See the [`common`](crate::common) module for when a group is controlled. Defaults to `None`.
+    #[prop_or_default]
+    pub selected: Option<T>,
+
+    /// The initially selected value of an uncontrolled group.
+    ///
+    /// Later changes are ignored. Defaults to `None`.
+    #[prop_or_default]
+    pub default_selected: Option<T>,
+
+    /// A state handle bound to the selection of the radio group.
     ///
-    /// This represents the current value selected in the group. It can be bound to a state
-    /// to reflect changes dynamically. Defaults to an empty string if not provided.
+    /// Takes precedence over `selected` and `default_selected`. Defaults to `None`.
     #[prop_or_default]
-    pub selected: String,
+    pub bind: Option<UseStateHandle<T>>,
 
     /// Callback for when the selected value changes.
     ///
     /// This callback is triggered whenever the user selects a different radio button. It
-    /// provides the new selected value as a string. Defaults to a no-op.
+    /// provides the new selected value. Defaults to a no-op.
     #[prop_or_default]
-    pub onchange: Callback<String>,
+    pub onchange: Callback<T>,
 
     /// Orientation of the radio buttons group (horizontal or vertical).
     ///
     /// Determines the layout of the radio buttons. The available options are:
     /// - `Orientation::Horizontal`: Displays the radio buttons side by side.
     /// - `Orientation::Vertical`: Stacks the radio buttons vertically.
-    ///   Defaults to `Orientation::Horizontal`.
+    /// - `Orientation::Grid`: Arranges the radio buttons in a grid of equal columns.
+    /// - `Orientation::Responsive`: Switches between orientations at container widths.
+    ///   Defaults to `Orientation::Vertical`.
     #[prop_or_default]
     pub orientation: Orientation,
 
+    /// Spacing between the radio buttons, on the theme's spacing scale.
+    ///
+    /// Overrides the gap of the orientation, which is `16px` for flex layouts and the gap of a
+    /// `Grid`. Defaults to `None`.
+    #[prop_or_default]
+    pub gap: Option<Size>,
+
+    /// Cross-axis alignment of the radio buttons.
+    ///
+    /// Rendered as `align-items`. Defaults to `None`, which keeps the browser default.
+    #[prop_or_default]
+    pub align: Option<Align>,
+
+    /// Main-axis distribution of the radio buttons.
+    ///
+    /// Rendered as `justify-content`. Defaults to `None`, which keeps the browser default.
+    #[prop_or_default]
+    pub justify: Option<Justify>,
+
+    /// Whether the radio buttons of a horizontal or vertical group wrap onto new lines.
+    ///
+    /// Lets horizontal groups flow onto several rows on narrow screens instead of
+    /// overflowing. Defaults to `false`.
+    #[prop_or_default]
+    pub wrap: bool,
+
     /// Additional inline styles for the container.
     ///
     /// Allows for custom inline styles to be applied directly to the group container.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// Additional CSS classes for the container.
     ///
     /// Enables adding custom CSS classes to the group container for further styling or
     /// overriding default styles. Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
+
+    /// Accessible name of the group.
+    ///
+    /// Rendered as `aria-label` on the group container. Prefer `aria_labelledby` when the
+    /// group has a visible heading. Omitted when empty.
+    #[prop_or_default]
+    pub aria_label: AttrValue,
+
+    /// ID of the element that labels the group.
+    ///
+    /// Rendered as `aria-labelledby` on the group container, e.g. the `id` of a visible
+    /// heading. Omitted when empty.
+    #[prop_or_default]
+    pub aria_labelledby: AttrValue,
+
+    /// ID of the element that describes the group.
+    ///
+    /// Rendered as `aria-describedby` on the group container. Omitted when empty.
+    #[prop_or_default]
+    pub aria_describedby: AttrValue,
+
+    /// Whether a selection is required.
+    ///
+    /// Exposes `aria-required` on the group and marks every child input as `required`.
+    /// Defaults to `false`.
+    #[prop_or_default]
+    pub required: bool,
+
+    /// Name shared by the `<input>` elements of the group.
+    ///
+    /// Used as the field name when the group is submitted as part of a `<form>`. When
+    /// empty, a unique name is generated so that groups never share a browser radio group.
+    #[prop_or_default]
+    pub name: AttrValue,
+
+    /// ID of the `<form>` the group's inputs belong to.
+    ///
+    /// Only needed when the group is rendered outside of the form element. Omitted when empty.
+    #[prop_or_default]
+    pub form: AttrValue,
+
+    /// Options rendered as `Radio` components.
+    ///
+    /// Lets the group be built from a collection, such as records loaded at runtime. One
+    /// `Radio` is rendered per option, before any `Radio` children. Defaults to no options.
+    #[prop_or_default]
+    pub options: Vec<RadioOption<T>>,
+
+    /// Size of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to `Size::XSmall`.
+    #[prop_or_default]
+    pub size: Size,
+
+    /// Styling type of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to `Type::None`.
+    #[prop_or_default]
+    pub r#type: Type,
+
+    /// Look of the radio buttons rendered from `options` and of child `Radio`
+    /// components that keep `Variant::Default`.
+    ///
+    /// `Variant::Segmented` renders the group as a segmented control with a sliding
+    /// highlight. Defaults to `Variant::Default`.
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Whether the selected card rendered from `options` shows a check mark.
+    ///
+    /// Only applies to `Variant::Card`. Defaults to `false`.
+    #[prop_or_default]
+    pub check_mark: bool,
+
+    /// Inline styles for the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub radio_style: AttrValue,
+
+    /// CSS class for the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub radio_class: AttrValue,
+
+    /// Inline styles applied to the selected radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub selected_style: AttrValue,
+
+    /// CSS class applied to the selected radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub selected_class: AttrValue,
+
+    /// Inline styles applied to disabled radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub disabled_style: AttrValue,
+
+    /// CSS class applied to disabled radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub disabled_class: AttrValue,
+
+    /// Inline styles applied to the hovered radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub hover_style: AttrValue,
+
+    /// CSS class applied to the hovered radio button rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub hover_class: AttrValue,
+
+    /// Inline styles for the descriptions of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub description_style: AttrValue,
+
+    /// CSS class for the descriptions of the radio buttons rendered from `options`.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub description_class: AttrValue,
 
     /// Child components for the group.
     ///
@@ -54,7 +239,7 @@ pub struct GroupProps {
     /// `Group` component. The children will be arranged based on the specified `orientation`.
     /// Defaults to an empty list of children if not provided.
     #[prop_or_default]
-    pub children: ChildrenWithProps<Radio>,
+    pub children: ChildrenWithProps<Radio<T>>,
 }
 
 /// Group Component
@@ -66,16 +251,33 @@ pub struct GroupProps {
 /// # Properties
 /// The component uses the `GroupProps` struct for its properties. Key properties include:
 ///
-/// - **selected**: The selected value of the radio group (`String`). Default: `""`.
-/// - **onchange**: Callback triggered when the selected value changes (`Callback<String>`). Default: no-op.
-/// - **orientation**: The layout of the radio buttons (horizontal or vertical) (`Orientation`). Default: `Orientation::Horizontal`.
-/// - **style**: Custom inline styles for the container (`&'static str`). Default: `""`.
-/// - **class**: Additional CSS classes for the container (`&'static str`). Default: `""`.
-/// - **children**: A collection of `Radio` components as children (`ChildrenWithProps<Radio>`). Default: empty.
+/// - **selected**: The selected value of a controlled group (`Option<T>`). Default: `None`.
+/// - **default_selected**: The initially selected value of an uncontrolled group (`Option<T>`). Default: `None`.
+/// - **bind**: A state handle the group reads its selection from and sets new selections on (`Option<UseStateHandle<T>>`). Default: `None`.
+/// - **onchange**: Callback triggered when the selected value changes (`Callback<T>`). Default: no-op.
+/// - **orientation**: The layout of the radio buttons (horizontal, vertical, grid or responsive) (`Orientation`). Default: `Orientation::Vertical`.
+/// - **gap**: Spacing between the radio buttons (`Option<Size>`). Default: `None`.
+/// - **align**, **justify**: Cross-axis alignment and main-axis distribution (`Option<Align>`, `Option<Justify>`). Default: `None`.
+/// - **wrap**: Whether the radio buttons wrap onto new lines (`bool`). Default: `false`.
+/// - **style**: Custom inline styles for the container (`AttrValue`). Default: `""`.
+/// - **class**: Additional CSS classes for the container (`AttrValue`). Default: `""`.
+/// - **aria_label**, **aria_labelledby**, **aria_describedby**: Accessible name and description of the group (`AttrValue`). Default: `""`.
+/// - **required**: Whether a selection is required (`bool`). Default: `false`.
+/// - **name**: Name shared by the group's inputs (`AttrValue`). Default: a generated unique name.
+/// - **form**: ID of the `<form>` the inputs belong to (`AttrValue`). Default: `""`.
+/// - **options**: Options rendered as `Radio` components before the children (`Vec<RadioOption<T>>`). Default: empty.
+/// - **size**, **r#type**, **variant**, **check_mark**: Size, styling type, look and check mark of the radio buttons
+///   rendered from `options` (`Size`, `Type`, `Variant`, `bool`).
+/// - **radio_style**, **radio_class**, **selected_style**, **selected_class**, **disabled_style**, **disabled_class**,
+///   **hover_style**, **hover_class**, **description_style**, **description_class**: Styles and classes of the radio buttons
+///   rendered from `options` (`AttrValue`). Default: `""`.
+/// - **children**: A collection of `Radio` components as children (`ChildrenWithProps<Radio<T>>`). Default: empty.
 ///
 /// # Features
 /// - Supports dynamic selection of radio buttons with state binding.
 /// - Allows horizontal or vertical orientation via the `Orientation` enum.
+/// - Typed gap, alignment, justification and wrapping that compose with any orientation.
+/// - A segmented control look through `Variant::Segmented`.
 /// - Customizable through inline styles and CSS classes.
 /// - Only accepts `Radio` components as children.
 ///
@@ -90,15 +292,9 @@ pub struct GroupProps {
 /// #[function_component(App)]
 /// pub fn app() -> Html {
 ///     let selected = use_state(|| "Option1".to_string());
-///     let on_change = {
-///         let selected = selected.clone();
-///         Callback::from(move |new_value: String| {
-///             selected.set(new_value);
-///         })
-///     };
 ///
 ///     html! {
-///         <Group selected={(*selected).clone()} onchange={on_change}>
+///         <Group bind={selected}>
 ///             <Radio value="Option1" label="Option 1" />
 ///             <Radio value="Option2" label="Option 2" />
 ///             <Radio value="Option3" label="Option 3" />
@@ -115,11 +311,9 @@ pub struct GroupProps {
 ///
 /// #[function_component(App)]
 /// pub fn app() -> Html {
-///     let selected = use_state(|| "Option1".to_string());
-///
 ///     html! {
 ///         <Group
-///             selected={(*selected).clone()}
+///             default_selected="Option1"
 ///             orientation={Orientation::Vertical}
 ///             style="margin: 20px"
 ///         >
@@ -131,6 +325,153 @@ pub struct GroupProps {
 /// }
 /// ```
 ///
+/// ## Grid Layout
+/// A 3-column plan picker that collapses to a single column on narrow containers:
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, Radio};
+/// use radiors::Orientation;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Group
+///             default_selected="basic"
+///             orientation={Orientation::responsive([
+///                 (0, Orientation::Vertical),
+///                 (640, Orientation::grid(3)),
+///             ])}
+///         >
+///             <Radio value="basic" label="Basic" />
+///             <Radio value="pro" label="Pro" />
+///             <Radio value="enterprise" label="Enterprise" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
+/// ## Wrapping Layout
+/// A horizontal group that wraps onto new rows instead of overflowing narrow screens:
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, Radio};
+/// use radiors::{Align, Orientation, Size};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Group
+///             default_selected="red"
+///             orientation={Orientation::Horizontal}
+///             gap={Size::Small}
+///             align={Align::Center}
+///             wrap=true
+///         >
+///             <Radio value="red" label="Red" />
+///             <Radio value="green" label="Green" />
+///             <Radio value="blue" label="Blue" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
+/// ## Segmented Control
+/// A compact row of joined segments with a highlight that slides to the selection:
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, Radio};
+/// use radiors::Variant;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Group default_selected="week" variant={Variant::Segmented}>
+///             <Radio value="day" label="Day" />
+///             <Radio value="week" label="Week" />
+///             <Radio value="month" label="Month" />
+///         </Group>
+///     }
+/// }
+/// ```
+///
+/// ## Typed Values
+/// `Group` and `Radio` are generic over their value type, which defaults to `String`.
+/// Any `Clone + PartialEq + Display` type, such as an enum, can be bound directly:
+///
+/// ```rust
+/// use std::fmt;
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, Radio};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Plan {
+///     Basic,
+///     Pro,
+/// }
+///
+/// impl fmt::Display for Plan {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str(match self {
+///             Plan::Basic => "basic",
+///             Plan::Pro => "pro",
+///         })
+///     }
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let plan = use_state(|| Plan::Basic);
+///
+///     html! {
+///         <Group<Plan> bind={plan}>
+///             <Radio<Plan> value={Plan::Basic} label="Basic" />
+///             <Radio<Plan> value={Plan::Pro} label="Pro" />
+///         </Group<Plan>>
+///     }
+/// }
+/// ```
+///
+/// ## Options from Data
+/// Groups loaded at runtime can be rendered from a collection of `RadioOption`s instead of
+/// hand-written `Radio` children:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::Group;
+/// use radiors::{RadioOption, Size, Type};
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct ShippingProps {
+///     pub methods: Vec<(u32, String, bool)>,
+/// }
+///
+/// #[function_component(Shipping)]
+/// pub fn shipping(props: &ShippingProps) -> Html {
+///     let selected = use_state(|| None::<u32>);
+///     let onchange = {
+///         let selected = selected.clone();
+///         Callback::from(move |id: u32| selected.set(Some(id)))
+///     };
+///     let options = props
+///         .methods
+///         .iter()
+///         .map(|(id, name, available)| {
+///             RadioOption::new(*id, name.clone()).with_disabled(!available)
+///         })
+///         .collect::<Vec<_>>();
+///
+///     html! {
+///         <Group<u32>
+///             selected={*selected}
+///             onchange={onchange}
+///             options={options}
+///             size={Size::Medium}
+///             r#type={Type::Primary}
+///         />
+///     }
+/// }
+/// ```
+///
 /// ## Custom Styling
 /// ```rust
 /// use yew::prelude::*;
@@ -140,7 +481,7 @@ pub struct GroupProps {
 /// pub fn app() -> Html {
 ///     html! {
 ///         <Group
-///             selected="Option1"
+///             default_selected="Option1"
 ///             style="border: 1px solid black; padding: 10px"
 ///             class="custom-radio-group"
 ///         >
@@ -153,40 +494,260 @@ pub struct GroupProps {
 ///
 /// # Behavior
 /// - The `Group` component dynamically manages the selection of its child `Radio` components.
-/// - Clicking a radio button updates the `selected` value and triggers the `onchange` callback.
-/// - The `orientation` property defines whether the radio buttons are arranged horizontally or vertically.
+/// - Clicking an enabled radio button other than the selected one triggers the `onchange` callback.
+/// - `selected`, `default_selected` and `bind` follow the selection rules of the [`common`](crate::common) module.
+/// - The `orientation` property defines whether the radio buttons are arranged horizontally, vertically or in a grid.
+///   A responsive orientation observes the width of the container and applies the matching layout.
+/// - `gap`, `align`, `justify` and `wrap` are applied on top of the resolved orientation.
+/// - Inside a `ThemeProvider`, the container carries the theme as `--radiors-*` CSS variables.
+/// - The inputs share the group's `name`, so the selection is submitted with its `<form>`, and resetting the form resets the group.
+/// - The container is rendered with `role="radiogroup"` and each `Radio` with `role="radio"` and `aria-checked`.
+/// - Only one radio button is in the tab order at a time: the selected one, or the first enabled one.
+/// - Arrow keys select the next or previous enabled radio button, wrapping at the ends, while `Home`
+///   and `End` jump to the first and last enabled one. `Space` selects the focused radio button.
 ///
 /// # Notes
 /// - The `children` property is required to be of type `Radio`. Passing other components will result in a compilation error.
-/// - The `onchange` callback receives the `value` of the newly selected `Radio` as a `String`.
+/// - The `onchange` callback receives the `value` of the newly selected `Radio`.
+/// - The value type `T` defaults to `String`. Other value types must be spelled out on both the
+///   `Group` and its `Radio` children, e.g. `<Group<Plan>>` and `<Radio<Plan>>`.
 /// - Custom styles and classes can be used to enhance the layout and appearance of the group container.
-#[function_component(Group)]
-pub fn group(props: &GroupProps) -> Html {
-    let selected = props.selected.clone();
-    let onchange = props.onchange.clone();
+/// - Text properties such as `style`, `class` and `aria_label` accept string literals, `String`s and `AttrValue`s.
+pub struct Group<T: RadioValue = String> {
+    _value: PhantomData<T>,
+}
+
+impl<T: RadioValue> Component for Group<T> {
+    type Message = ();
+    type Properties = GroupProps<T>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _value: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props().clone();
+        html! { <GroupView<T> ..props /> }
+    }
+}
+
+/// Renders a `Group`.
+///
+/// `Group` is a struct component so that its value type can default to `String`,
+/// which generic function components cannot express.
+#[function_component(GroupView)]
+fn group_view<T: RadioValue>(props: &GroupProps<T>) -> Html {
+    let node = use_node_ref();
+    let theme = use_context::<Theme>();
+    let width = use_state_eq(|| None::<u32>);
+    use_effect_with(props.orientation.is_responsive(), {
+        let node = node.clone();
+        let width = width.clone();
+        move |responsive| {
+            let listener = responsive
+                .then(|| node.cast::<web_sys::Element>())
+                .flatten()
+                .and_then(|container| {
+                    WidthListener::new(&container, move |value| width.set(Some(value)))
+                });
+            move || drop(listener)
+        }
+    });
+    let layout = GroupLayout {
+        variant: props.variant,
+        r#type: &props.r#type,
+        orientation: props.orientation.resolve(*width),
+        gap: props.gap.as_ref(),
+        align: props.align,
+        justify: props.justify,
+        wrap: props.wrap,
+    };
+    let ids = use_context::<IdCounter>().unwrap_or_else(IdCounter::thread);
+    let generated_name = use_memo((), |_| LazyId::group_name(ids));
+    let name = match non_empty(&props.name) {
+        Some(name) => name,
+        None => AttrValue::from(generated_name.get().to_string()),
+    };
+    let bind = props.bind.clone();
+    let selected = match &bind {
+        Some(bind) => Some((**bind).clone()),
+        None => props.selected.clone(),
+    };
+    let initial = use_memo((), {
+        let selected = selected.clone().or_else(|| props.default_selected.clone());
+        |_| selected
+    });
+    let uncontrolled = use_state(|| props.default_selected.clone());
+    // Once `selected` has held a value, the group stays controlled, so setting it back to
+    // `None` clears the selection instead of handing it back to the group.
+    let latched = use_mut_ref(|| false);
+    let controlled = {
+        let mut latched = latched.borrow_mut();
+        *latched |= selected.is_some();
+        *latched
+    };
+    let focused = use_state(|| None::<T>);
+    let mut state = match (*initial).clone() {
+        Some(initial) => RadioGroupState::default().with_selected(initial),
+        None => RadioGroupState::default(),
+    }
+    .with_required(props.required);
+    let option_type = match props.variant {
+        Variant::Segmented => Type::None,
+        _ => props.r#type.clone(),
+    };
+    let radios = props
+        .options
+        .iter()
+        .map(|option| {
+            html_nested! {
+                <Radio<T>
+                    key={option.value.to_value()}
+                    value={option.value.clone()}
+                    label={option.label.clone()}
+                    src={option.src.clone()}
+                    description={option.description.clone()}
+                    disabled={option.disabled}
+                    size={props.size.clone()}
+                    r#type={option_type.clone()}
+                    variant={props.variant}
+                    check_mark={props.check_mark}
+                    style={props.radio_style.clone()}
+                    class={props.radio_class.clone()}
+                    selected_style={props.selected_style.clone()}
+                    selected_class={props.selected_class.clone()}
+                    disabled_style={props.disabled_style.clone()}
+                    disabled_class={props.disabled_class.clone()}
+                    hover_style={props.hover_style.clone()}
+                    hover_class={props.hover_class.clone()}
+                    description_style={props.description_style.clone()}
+                    description_class={props.description_class.clone()}
+                />
+            }
+        })
+        .chain(props.children.iter())
+        .collect::<Vec<_>>();
+    for radio in &radios {
+        state.register(radio.props.value.clone(), radio.props.disabled);
+    }
+    state.set_selected(if controlled {
+        selected
+    } else {
+        (*uncontrolled).clone()
+    });
+    if let Some(value) = &*focused {
+        state.focus(value);
+    }
+    let onchange = {
+        let onchange = props.onchange.clone();
+        let uncontrolled = uncontrolled.clone();
+        Callback::from(move |value: T| {
+            if let Some(bind) = &bind {
+                bind.set(value.clone());
+            } else if !controlled {
+                uncontrolled.set(Some(value.clone()));
+            }
+            onchange.emit(value);
+        })
+    };
+    let highlight = (props.variant == Variant::Segmented)
+        .then(|| segment_highlight_style(&state))
+        .flatten();
+    let group_variant = props.variant;
+
+    // `Callback`s compare by pointer, so the listener reads the latest `onchange` from a
+    // ref instead of being attached again on every render.
+    let reset_onchange = use_mut_ref(|| onchange.clone());
+    *reset_onchange.borrow_mut() = onchange.clone();
+    use_effect_with((state.clone(), controlled, props.form.clone()), {
+        let node = node.clone();
+        move |(state, controlled, form)| {
+            let controlled = *controlled;
+            let listener = node.cast::<web_sys::Element>().and_then(|container| {
+                let state = state.clone();
+                FormResetListener::new(&container, form, move || {
+                    let mut state = state.clone();
+                    if state.reset() {
+                        match state.selected() {
+                            Some(value) => reset_onchange.borrow().emit(value.clone()),
+                            // There is no value to emit, so only an uncontrolled group
+                            // follows a reset to no selection.
+                            None if !controlled => uncontrolled.set(None),
+                            None => {}
+                        }
+                    }
+                })
+            });
+            move || drop(listener)
+        }
+    });
+
+    let onkeydown = {
+        let state = state.clone();
+        let focused = focused.clone();
+        let onchange = onchange.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let mut state = state.clone();
+            let Some(changed) = state.handle_key(&e.key()) else {
+                return;
+            };
+            e.prevent_default();
+            focused.set(state.focused().cloned());
+            if changed {
+                if let Some(value) = state.selected() {
+                    onchange.emit(value.clone());
+                }
+            }
+        })
+    };
 
     html! {
         <div
-            class={props.class}
-            style={format!(
-                "{} {}",
-                props.orientation.to_style(),
-                props.style
-            )}
+            ref={node}
+            role="radiogroup"
+            aria-label={non_empty(&props.aria_label)}
+            aria-labelledby={non_empty(&props.aria_labelledby)}
+            aria-describedby={non_empty(&props.aria_describedby)}
+            aria-required={props.required.then_some("true")}
+            class={class_list([layout.to_class().as_str(), &props.class])}
+            style={inline_style([
+                theme.map(|theme| theme.to_style()).unwrap_or_default().as_str(),
+                &layout.to_style(),
+                &props.style,
+            ])}
+            onkeydown={onkeydown}
         >
-            { for props.children.iter().map(|mut child| {
+            if let Some(style) = highlight {
+                <span aria-hidden="true" style={style} class={class_list([SEGMENT_HIGHLIGHT_CLASS])}></span>
+            }
+            { for radios.into_iter().map(|mut child| {
+                let group_form = non_empty(&props.form);
                 let props = Rc::make_mut(&mut child.props);
-                let is_selected = props.value == selected;
+                let value = props.value.clone();
                 let onclick = {
                     let onchange = onchange.clone();
-                    let value = props.value.to_string();
-                    Callback::from(move |_| {
-                        onchange.emit(value.clone());
+                    let state = state.clone();
+                    let focused = focused.clone();
+                    Callback::from(move |value: T| {
+                        let mut state = state.clone();
+                        let changed = state.select(&value);
+                        focused.set(Some(value.clone()));
+                        if changed {
+                            onchange.emit(value);
+                        }
                     })
                 };
 
-                props.selected = is_selected;
+                props.selected = state.is_selected(&value);
+                props.focused = state.is_focused(&value);
+                props.tab_stop = state.is_tab_stop(&value);
+                props.required |= state.is_required();
+                props.name = name.clone();
+                props.form = props.form.take().or(group_form);
                 props.on_click = onclick;
+                props.variant = effective_variant(props.variant, group_variant);
 
                 child
             }) }
@@ -199,70 +760,107 @@ pub fn group(props: &GroupProps) -> Html {
 /// The `Radio` component allows the creation of individual, customizable radio buttons.
 /// It supports various configurations for appearance, behavior, and styles.
 #[derive(Properties, Clone, PartialEq)]
-pub struct RadioProps {
+pub struct RadioProps<T: RadioValue = String> {
     /// The label for the radio button.
     ///
     /// Defines the text displayed next to the radio button.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub label: &'static str,
+    pub label: AttrValue,
 
     /// The value for the radio button.
     ///
     /// This value represents the data associated with the radio button, used to identify
-    /// it in the `Group`'s selection context.
-    /// Defaults to an empty string if not provided.
-    #[prop_or_default]
-    pub value: &'static str,
+    /// it in the `Group`'s selection context. Its string form, given by `RadioValue::to_value`,
+    /// is used as the `value` attribute of the underlying `<input>`.
+    pub value: T,
 
     /// Optional image URL for the radio button.
     ///
     /// If provided, the radio button can display an image along with its label.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub src: &'static str,
+    pub src: AttrValue,
+
+    /// Secondary text for the radio button.
+    ///
+    /// Rendered under the label, e.g. to explain the option in more detail, and referenced
+    /// by the radio button's `aria-describedby`. Omitted when empty.
+    #[prop_or_default]
+    pub description: AttrValue,
+
+    /// Inline styles for the description.
+    ///
+    /// Applied after the built-in description styles. Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub description_style: AttrValue,
+
+    /// CSS class for the description.
+    ///
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub description_class: AttrValue,
+
+    /// Content rendered before the label, such as an icon.
+    ///
+    /// Defaults to no content.
+    #[prop_or_default]
+    pub leading: Html,
+
+    /// Content rendered after the label, such as a badge or a price.
+    ///
+    /// Defaults to no content.
+    #[prop_or_default]
+    pub trailing: Html,
+
+    /// Rich label content.
+    ///
+    /// Rendered inside the label element after the `label` text, so formatted text can
+    /// replace or extend it. Defaults to no content.
+    #[prop_or_default]
+    pub children: Html,
 
     /// Inline styles for the container.
     ///
     /// Allows applying custom inline CSS styles directly to the radio button's container.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// CSS class for the container.
     ///
     /// Adds custom CSS classes to style the radio button's container.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
 
     /// Inline styles for the label.
     ///
     /// Enables the addition of custom inline CSS styles to the label text.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub label_style: &'static str,
+    pub label_style: AttrValue,
 
     /// CSS class for the label.
     ///
     /// Adds custom CSS classes to style the radio button's label.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub label_class: &'static str,
+    pub label_class: AttrValue,
 
     /// Inline styles for the image.
     ///
     /// Allows applying custom inline CSS styles to the image associated with the radio button.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub image_style: &'static str,
+    pub image_style: AttrValue,
 
     /// CSS class for the image.
     ///
     /// Adds custom CSS classes to style the image associated with the radio button.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub image_class: &'static str,
+    pub image_class: AttrValue,
 
     /// The size of the radio button.
     ///
@@ -282,6 +880,19 @@ pub struct RadioProps {
     #[prop_or_default]
     pub r#type: Type,
 
+    /// The look of the radio button.
+    ///
+    /// `Variant::Card` renders a bordered card for plan and product pickers.
+    /// Defaults to `Variant::Default` if not provided.
+    #[prop_or_default]
+    pub variant: Variant,
+
+    /// Whether a selected card shows a check mark in its corner.
+    ///
+    /// Only applies to `Variant::Card`. Defaults to `false`.
+    #[prop_or_default]
+    pub check_mark: bool,
+
     /// Whether this radio button is selected.
     ///
     /// Indicates if the radio button is currently selected. Managed internally in the `Group`
@@ -296,78 +907,154 @@ pub struct RadioProps {
     #[prop_or_default]
     pub disabled: bool,
 
+    /// Whether a selection is required.
+    ///
+    /// Marks the underlying `<input>` as `required`. Inside a `Group`, the group's
+    /// `required` property also applies. Defaults to `false`.
+    #[prop_or_default]
+    pub required: bool,
+
+    /// Name of the underlying `<input>`.
+    ///
+    /// Used as the field name when submitted as part of a `<form>`. Inside a `Group`,
+    /// the group's name is used instead. Omitted when empty.
+    #[prop_or_default]
+    pub name: AttrValue,
+
+    /// ID of the `<form>` the underlying `<input>` belongs to.
+    ///
+    /// Inside a `Group`, defaults to the group's `form`. Omitted when not provided.
+    #[prop_or_default]
+    pub form: Option<AttrValue>,
+
     /// Inline styles applied when the radio button is selected.
     ///
     /// Provides custom styles for the radio button in the selected state.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub selected_style: &'static str,
+    pub selected_style: AttrValue,
 
     /// CSS class applied when the radio button is selected.
     ///
     /// Adds custom CSS classes to style the radio button in the selected state.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub selected_class: &'static str,
+    pub selected_class: AttrValue,
 
     /// Inline styles applied when the radio button is disabled.
     ///
     /// Provides custom styles for the radio button in the disabled state.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub disabled_style: &'static str,
+    pub disabled_style: AttrValue,
 
     /// CSS class applied when the radio button is disabled.
     ///
     /// Adds custom CSS classes to style the radio button in the disabled state.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub disabled_class: &'static str,
+    pub disabled_class: AttrValue,
 
     /// Inline styles for animations applied to the radio button.
     ///
     /// Enables applying custom styles to animations or transitions for the radio button.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub animation_style: &'static str,
+    pub animation_style: AttrValue,
 
     /// CSS class for animations applied to the radio button.
     ///
     /// Adds custom CSS classes to handle animations or transitions for the radio button.
     /// Defaults to an empty string if not provided.
     #[prop_or_default]
-    pub animation_class: &'static str,
+    pub animation_class: AttrValue,
+
+    /// Inline styles applied while the pointer is over the radio button.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub hover_style: AttrValue,
+
+    /// CSS class applied while the pointer is over the radio button.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub hover_class: AttrValue,
+
+    /// Inline styles applied while the radio button is being pressed.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub active_style: AttrValue,
+
+    /// CSS class applied while the radio button is being pressed.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub active_class: AttrValue,
+
+    /// Inline styles applied while the radio button has keyboard focus.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub focus_style: AttrValue,
+
+    /// CSS class applied while the radio button has keyboard focus.
+    ///
+    /// Not applied while the radio button is disabled.
+    /// Defaults to an empty string if not provided.
+    #[prop_or_default]
+    pub focus_class: AttrValue,
 
     /// Inline styles for the hidden input element.
     ///
     /// Provides custom styles for the hidden `<input>` element used for the radio button.
     /// Defaults to `HIDDEN_INPUT_STYLE`.
-    #[prop_or(HIDDEN_INPUT_STYLE)]
-    pub input_style: &'static str,
+    #[prop_or(AttrValue::Static(HIDDEN_INPUT_STYLE))]
+    pub input_style: AttrValue,
 
     /// CSS class for the hidden input element.
     ///
     /// Adds custom CSS classes for the hidden `<input>` element.
-    /// Defaults to an empty string if not provided.
-    #[prop_or_default]
-    pub input_class: &'static str,
+    /// Defaults to `radiors-radio__input` with the `stylesheet` feature, and to an empty string otherwise.
+    #[prop_or(AttrValue::Static(HIDDEN_INPUT_CLASS))]
+    pub input_class: AttrValue,
 
     /// Callback for when the radio button is clicked.
     ///
     /// Triggered whenever the user clicks on the radio button. It provides the `value` of the
-    /// radio button as a string to the callback.
+    /// radio button to the callback.
     /// Defaults to a no-op.
     #[prop_or_default]
-    pub onclick: Callback<String>,
+    pub onclick: Callback<T>,
 
     /// Internal callback triggered when the radio button is clicked.
     ///
     /// This property is intended for use by the parent `Group` component to manage
     /// the state of the radio group. It is not exposed for direct use by end users.
     ///
-    /// The callback receives the `value` of the clicked radio button as a `String`.
+    /// The callback receives the `value` of the clicked radio button.
     #[prop_or_default]
-    on_click: Callback<String>,
+    on_click: Callback<T>,
+
+    /// Whether this radio button holds the keyboard focus within its `Group`.
+    ///
+    /// Managed by the parent `Group`, which moves DOM focus to the radio button when
+    /// this becomes `true`.
+    #[prop_or_default]
+    focused: bool,
+
+    /// Whether this radio button is the one reachable with the Tab key.
+    ///
+    /// Managed by the parent `Group` to implement a roving tabindex. Standalone radio
+    /// buttons are always reachable unless disabled.
+    #[prop_or(true)]
+    tab_stop: bool,
 }
 
 /// Radio Component
@@ -378,17 +1065,31 @@ pub struct RadioProps {
 /// # Properties
 /// The component uses the `RadioProps` struct for its properties. Key properties include:
 ///
-/// - **label**: The text displayed next to the radio button (`&'static str`). Default: `""`.
-/// - **value**: The value of the radio button (`&'static str`). Default: `""`.
-/// - **src**: Optional image URL to display next to the radio button (`&'static str`). Default: `""`.
-/// - **style**: Custom inline styles for the radio button container (`&'static str`). Default: `""`.
-/// - **class**: CSS class for the radio button container (`&'static str`). Default: `""`.
+/// - **label**: The text displayed next to the radio button (`AttrValue`). Default: `""`.
+/// - **value**: The value of the radio button (`T`, `String` by default). Required.
+/// - **src**: Optional image URL to display next to the radio button (`AttrValue`). Default: `""`.
+/// - **description**: Secondary text rendered under the label (`AttrValue`). Default: `""`.
+/// - **description_style**, **description_class**: Styles and classes of the description (`AttrValue`). Default: `""`.
+/// - **leading**, **trailing**: Content rendered before and after the label (`Html`). Default: empty.
+/// - **children**: Rich label content rendered after the `label` text (`Html`). Default: empty.
+/// - **style**: Custom inline styles for the radio button container (`AttrValue`). Default: `""`.
+/// - **class**: CSS class for the radio button container (`AttrValue`). Default: `""`.
 /// - **selected**: Whether the radio button is currently selected (`bool`). Default: `false`.
 /// - **disabled**: Whether the radio button is disabled (`bool`). Default: `false`.
-/// - **onclick**: Callback triggered when the radio button is clicked (`Callback<String>`). Default: no-op.
+/// - **required**: Whether the underlying input is required (`bool`). Default: `false`.
+/// - **name**: Name of the underlying input (`AttrValue`). Default: `""`, or the group's name inside a `Group`.
+/// - **form**: ID of the `<form>` the input belongs to (`Option<AttrValue>`). Default: `None`.
+/// - **onclick**: Callback triggered when the radio button is clicked (`Callback<T>`). Default: no-op.
+/// - **hover_style**, **hover_class**: Styles and classes applied while hovered (`AttrValue`). Default: `""`.
+/// - **active_style**, **active_class**: Styles and classes applied while pressed (`AttrValue`). Default: `""`.
+/// - **focus_style**, **focus_class**: Styles and classes applied while focused (`AttrValue`). Default: `""`.
+/// - **variant**: The look of the radio button (`Variant`). Default: `Variant::Default`.
+/// - **check_mark**: Whether a selected card shows a check mark (`bool`). Default: `false`.
 ///
 /// # Features
 /// - Customizable label text and optional images.
+/// - Rich content through the `leading` and `trailing` slots and children.
+/// - A card look for plan and product pickers through `Variant::Card`.
 /// - Inline styles and CSS classes for styling.
 /// - Supports disabled and selected states.
 /// - Works seamlessly within the `Group` component.
@@ -443,129 +1144,478 @@ pub struct RadioProps {
 /// }
 /// ```
 ///
+/// ## Rich Content
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::Radio;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Radio
+///             value="express"
+///             leading={html! { <i class="icon-truck"></i> }}
+///             trailing={html! { <strong>{ "$9.99" }</strong> }}
+///         >
+///             { "Express " }<span class="badge">{ "Fastest" }</span>
+///         </Radio>
+///     }
+/// }
+/// ```
+///
+/// ## Card
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::Radio;
+/// use radiors::{Size, Variant};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Radio
+///             value="pro"
+///             label="Pro"
+///             description="For growing teams"
+///             trailing={html! { <strong>{ "$29/mo" }</strong> }}
+///             variant={Variant::Card}
+///             size={Size::Large}
+///             selected=true
+///             check_mark=true
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Hover, Active and Focus States
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::Radio;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Radio
+///             label="Option 1"
+///             value="Option1"
+///             animation_style="transition: background-color 0.2s;"
+///             hover_style="background-color: #f0f0f0;"
+///             active_class="pressed"
+///             focus_style="outline: 2px solid #007bff;"
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Runtime Labels and Classes
+/// Text properties are `AttrValue`s, so owned strings computed at runtime can be passed as well:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::Radio;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct PlanProps {
+///     pub name: String,
+///     pub price: u32,
+/// }
+///
+/// #[function_component(Plan)]
+/// pub fn plan(props: &PlanProps) -> Html {
+///     html! {
+///         <Radio
+///             label={format!("{} (${}/month)", props.name, props.price)}
+///             value={props.name.clone()}
+///             class={format!("plan-{}", props.name.to_lowercase())}
+///         />
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - Clicking the radio button emits the `onclick` callback with its `value`.
 /// - `on_click` is managed internally by the `Group` component for handling selection state.
 /// - The `disabled` property prevents interaction when set to `true`.
+/// - Hovering, pressing and focusing the radio button add the `hover_*`, `active_*` and `focus_*` styles
+///   and classes after the other ones, so they take precedence. Disabled radio buttons ignore them.
+/// - A non-empty `description` is rendered with a generated `id` that the radio button's
+///   `aria-describedby` refers to, so assistive technology announces it after the label.
+/// - Clicks on `leading`, `trailing` and children content select the radio button like clicks on
+///   its label, and their text is part of the radio button's accessible name.
 ///
 /// # Notes
 /// - The `selected` and `on_click` properties are typically managed by the parent `Group` component.
-#[function_component(Radio)]
-pub fn radio(props: &RadioProps) -> Html {
+/// - Text properties such as `label`, `src`, `style` and `class` accept string literals, `String`s and `AttrValue`s.
+pub struct Radio<T: RadioValue = String> {
+    _value: PhantomData<T>,
+}
+
+impl<T: RadioValue> Component for Radio<T> {
+    type Message = ();
+    type Properties = RadioProps<T>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _value: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props().clone();
+        html! { <RadioView<T> ..props /> }
+    }
+}
+
+/// Renders a `Radio`, see `GroupView` for why this is split from the component.
+#[function_component(RadioView)]
+fn radio_view<T: RadioValue>(props: &RadioProps<T>) -> Html {
+    let node = use_node_ref();
+    let hovered = use_state_eq(|| false);
+    let active = use_state_eq(|| false);
+    let has_focus = use_state_eq(|| false);
+    let ids = use_context::<IdCounter>().unwrap_or_else(IdCounter::thread);
+    let description_id = use_memo((), |_| LazyId::description(ids));
+    let description_id =
+        (!props.description.is_empty()).then(|| AttrValue::from(description_id.get().to_string()));
+    use_effect_with(props.focused, {
+        let node = node.clone();
+        move |focused| {
+            if *focused {
+                if let Some(element) = node.cast::<web_sys::HtmlElement>() {
+                    let _ = element.focus();
+                }
+            }
+        }
+    });
+
     let onclick = {
         let on_click = props.on_click.clone();
         let onclick = props.onclick.clone();
-        let value = props.value.to_string();
+        let value = props.value.clone();
         let disabled = props.disabled;
-        Callback::from(move |e: MouseEvent| {
-            e.prevent_default();
+        Callback::from(move |_: MouseEvent| {
             if !disabled {
                 on_click.emit(value.clone());
                 onclick.emit(value.clone());
             }
         })
     };
-    let selected = props.selected;
-    let disabled = props.disabled;
-
-    let selected_style = props.selected_style;
-    let disabled_style = props.disabled_style;
-    let style = props.style;
-    let animation_style = props.animation_style;
+    let selected_style = if props.selected {
+        props.selected_style.as_str()
+    } else {
+        ""
+    };
+    let disabled_style = if props.disabled {
+        props.disabled_style.as_str()
+    } else {
+        ""
+    };
+    let selected_class = if props.selected {
+        props.selected_class.as_str()
+    } else {
+        ""
+    };
+    let disabled_class = if props.disabled {
+        props.disabled_class.as_str()
+    } else {
+        ""
+    };
 
-    let selected_class = props.selected_class;
-    let disabled_class = props.disabled_class;
-    let class = props.class;
-    let animation_class = props.animation_class;
+    let interactive = !props.disabled;
+    let hover_style = if *hovered && interactive {
+        props.hover_style.as_str()
+    } else {
+        ""
+    };
+    let hover_class = if *hovered && interactive {
+        props.hover_class.as_str()
+    } else {
+        ""
+    };
+    let active_style = if *active && interactive {
+        props.active_style.as_str()
+    } else {
+        ""
+    };
+    let active_class = if *active && interactive {
+        props.active_class.as_str()
+    } else {
+        ""
+    };
+    let focus_style = if *has_focus && interactive {
+        props.focus_style.as_str()
+    } else {
+        ""
+    };
+    let focus_class = if *has_focus && interactive {
+        props.focus_class.as_str()
+    } else {
+        ""
+    };
 
-    let size = props.size.to_style();
-    let style_type = props.r#type.to_style();
+    let size = size_style(&props.size);
+    let style_type = type_style(&props.r#type);
+    let builtin_class = radio_class(
+        &props.variant,
+        &props.r#type,
+        &props.size,
+        props.selected,
+        props.disabled,
+    );
+    let themed_style = themed_radio_style(props.selected, *has_focus && interactive);
+    let variant = variant_style(&props.variant, props.selected);
+    let check_mark = props.variant == Variant::Card && props.check_mark && props.selected;
 
     html! {
         <div
-            class={format!(
-                "{} {} {}",
-                if props.selected { props.selected_class } else { "" },
-                if props.disabled { props.disabled_class } else { "" },
-                props.class,
-            )}
-            style={format!(
-                "{} {} {} {} {} {}",
-                if props.selected { props.selected_style } else { "" },
-                if props.disabled { props.disabled_style } else { "" },
-                props.style,
-                props.animation_style,
-                style_type,
-                size,
-            )}
+            ref={node}
+            role="radio"
+            aria-checked={if props.selected { "true" } else { "false" }}
+            aria-disabled={props.disabled.then_some("true")}
+            aria-describedby={description_id.clone()}
+            tabindex={if props.tab_stop && !props.disabled { "0" } else { "-1" }}
+            class={class_list([
+                builtin_class.as_str(),
+                selected_class,
+                disabled_class,
+                &props.class,
+                &props.animation_class,
+                hover_class,
+                focus_class,
+                active_class,
+            ])}
+            style={inline_style([
+                themed_style.as_str(),
+                &variant,
+                selected_style,
+                disabled_style,
+                &props.style,
+                &props.animation_style,
+                &style_type,
+                &size,
+                hover_style,
+                focus_style,
+                active_style,
+            ])}
             onclick={onclick}
-            onmouseover={let size = size.clone();
-                let style_type = style_type.clone();
-                Callback::from(move |e: MouseEvent| {
-                let target = e.target_dyn_into::<web_sys::HtmlElement>().unwrap();
-                if target.tag_name() == "DIV" {
-                    target.set_attribute("style", &format!(
-                        "{} {} {} {} {} {}",
-                        if selected { selected_style } else { "" },
-                        if disabled { disabled_style } else { "" },
-                        style,
-                        animation_style,
-                        size,
-                        style_type
-                    )).unwrap();
-                    target.set_attribute("class", &format!(
-                        "{} {} {} {}",
-                        if selected { selected_class } else { "" },
-                        if disabled { disabled_class } else { "" },
-                        class,
-                        animation_class
-                    )).unwrap();
-                }
-            })}
-            onmouseleave={let size = size.clone();
-                let style_type = style_type.clone();
-                Callback::from(move |e: MouseEvent| {
-                let target = e.target_dyn_into::<web_sys::HtmlElement>().unwrap();
-                if target.tag_name() == "DIV" {
-                    target.set_attribute("style", &format!(
-                        "{} {} {} {} {}",
-                        if selected { selected_style } else { "" },
-                        if disabled { disabled_style } else { "" },
-                        style,
-                        size,
-                        style_type
-                    )).unwrap();
-                    target.set_attribute("class", &format!(
-                        "{} {} {}",
-                        if selected { selected_class } else { "" },
-                        if disabled { disabled_class } else { "" },
-                        class,
-                    )).unwrap();
-                }
-            })}
+            onmouseenter={{
+                let hovered = hovered.clone();
+                Callback::from(move |_: MouseEvent| hovered.set(true))
+            }}
+            onmouseleave={{
+                let hovered = hovered.clone();
+                let active = active.clone();
+                Callback::from(move |_: MouseEvent| {
+                    hovered.set(false);
+                    active.set(false);
+                })
+            }}
+            onmousedown={{
+                let active = active.clone();
+                Callback::from(move |_: MouseEvent| active.set(true))
+            }}
+            onmouseup={{
+                let active = active.clone();
+                Callback::from(move |_: MouseEvent| active.set(false))
+            }}
+            onfocus={{
+                let has_focus = has_focus.clone();
+                Callback::from(move |_: FocusEvent| has_focus.set(true))
+            }}
+            onblur={{
+                let has_focus = has_focus.clone();
+                Callback::from(move |_: FocusEvent| has_focus.set(false))
+            }}
         >
             <input
                 type="radio"
-                name="radio"
-                value={props.value}
+                name={non_empty(&props.name)}
+                form={props.form.clone()}
+                value={props.value.to_value()}
                 checked={props.selected}
                 disabled={props.disabled}
-                style={props.input_style}
-                class={props.input_class}
+                required={props.required}
+                tabindex="-1"
+                aria-hidden="true"
+                style={inline_style([&props.input_style])}
+                class={class_list([&props.input_class])}
             />
+            if check_mark {
+                <span aria-hidden="true" style={inline_style([CHECK_MARK_STYLE])} class={class_list([CHECK_MARK_CLASS])}>{ "✓" }</span>
+            }
             { if !props.src.is_empty() {
                 html! {
                     <img
-                        src={props.src}
-                        alt={props.label}
-                        style={props.image_style}
-                        class={props.image_class}
+                        src={props.src.clone()}
+                        alt={props.label.clone()}
+                        style={inline_style([&props.image_style])}
+                        class={class_list([&props.image_class])}
                     />
                 }
             } else {
                 html! {}
             } }
-            <span style={props.label_style} class={props.label_class}>{ props.label }</span>
+            { props.leading.clone() }
+            <span style={inline_style([&props.label_style])} class={class_list([&props.label_class])}>
+                { props.label.clone() }
+                { props.children.clone() }
+            </span>
+            { props.trailing.clone() }
+            if let Some(id) = description_id {
+                <span
+                    id={id}
+                    style={inline_style([DESCRIPTION_STYLE, &props.description_style])}
+                    class={class_list([DESCRIPTION_CLASS, &props.description_class])}
+                >
+                    { props.description.clone() }
+                </span>
+            }
         </div>
     }
 }
+
+/// Properties for configuring the `ThemeProvider` component.
+#[derive(Properties, Clone, PartialEq)]
+pub struct ThemeProviderProps {
+    /// The light theme provided to every `Group` below this component.
+    #[prop_or_default]
+    pub theme: Theme,
+
+    /// The dark theme provided to every `Group` below this component.
+    #[prop_or_else(Theme::dark)]
+    pub dark_theme: Theme,
+
+    /// Which of `theme` and `dark_theme` is applied.
+    #[prop_or_default]
+    pub color_scheme: ColorScheme,
+
+    /// Content rendered inside the provider.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// ThemeProvider Component
+///
+/// Provides a `Theme` to its descendants through Yew context. Every `Group` rendered
+/// below it emits the theme as `--radiors-*` CSS variables on its container, so
+/// changing the `theme` property restyles all of them at once.
+///
+/// # Properties
+/// - **theme**: The light `Theme` to provide. Default: `Theme::default()`.
+/// - **dark_theme**: The dark `Theme` to provide. Default: `Theme::dark()`.
+/// - **color_scheme**: Whether to apply the light or the dark theme, or to follow the browser's
+///   `prefers-color-scheme` preference. Default: `ColorScheme::System`.
+/// - **children**: Content rendered inside the provider.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, Radio, ThemeProvider};
+/// use radiors::{Swatch, Theme, Type};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let theme = Theme {
+///         primary: Swatch::new("#6d28d9", "#ffffff"),
+///         radius: "8px".into(),
+///         ..Theme::default()
+///     };
+///
+///     html! {
+///         <ThemeProvider theme={theme}>
+///             <Group default_selected="Option1">
+///                 <Radio value="Option1" label="Option 1" r#type={Type::Primary} />
+///                 <Radio value="Option2" label="Option 2" r#type={Type::Primary} />
+///             </Group>
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+///
+/// ## Forced Dark Mode
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, Radio, ThemeProvider};
+/// use radiors::ColorScheme;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <ThemeProvider color_scheme={ColorScheme::Dark}>
+///             <Group default_selected="Option1">
+///                 <Radio value="Option1" label="Option 1" />
+///                 <Radio value="Option2" label="Option 2" />
+///             </Group>
+///         </ThemeProvider>
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - The provider renders no element of its own. A `Radio` used outside of a `Group` reads the
+///   variables from any ancestor, e.g. one styled with `Theme::to_style`.
+/// - With `ColorScheme::System`, the light theme is rendered until the preference is read in the
+///   browser, and switching the system preference updates the groups right away.
+#[function_component(ThemeProvider)]
+pub fn theme_provider(props: &ThemeProviderProps) -> Html {
+    let prefers_dark = use_state_eq(|| false);
+    use_effect_with((), {
+        let prefers_dark = prefers_dark.clone();
+        move |_| {
+            let listener = ColorSchemeListener::new(move |dark| prefers_dark.set(dark));
+            move || drop(listener)
+        }
+    });
+    let theme = if props.color_scheme.is_dark(*prefers_dark) {
+        props.dark_theme.clone()
+    } else {
+        props.theme.clone()
+    };
+
+    html! {
+        <ContextProvider<Theme> context={theme}>
+            { props.children.clone() }
+        </ContextProvider<Theme>>
+    }
+}
+
+/// Properties for configuring the `IdScope` component.
+#[derive(Properties, Clone, PartialEq)]
+pub struct IdScopeProps {
+    /// Content rendered inside the scope.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// IdScope Component
+///
+/// Counts the names and IDs generated for the groups and radio buttons below it from zero.
+/// Wrap the root of a server-rendered app in it, so that each render generates the same
+/// names and IDs as hydration does in the browser. Outside of a scope, they are counted
+/// per thread.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use radiors::yew::{Group, IdScope, Radio};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <IdScope>
+///             <Group default_selected="Option1">
+///                 <Radio value="Option1" label="Option 1" description="The first option" />
+///                 <Radio value="Option2" label="Option 2" />
+///             </Group>
+///         </IdScope>
+///     }
+/// }
+/// ```
+#[function_component(IdScope)]
+pub fn id_scope(props: &IdScopeProps) -> Html {
+    let ids = use_memo((), |_| IdCounter::default());
+
+    html! {
+        <ContextProvider<IdCounter> context={(*ids).clone()}>
+            { props.children.clone() }
+        </ContextProvider<IdCounter>>
+    }
+}
@@ -0,0 +1,431 @@
+#![cfg(all(feature = "ssr", feature = "yew", feature = "dio", feature = "lep"))]
+
+//! Renders the same logical groups through every adapter and checks that the markup
+//! agrees on structure, attributes and ARIA state.
+
+use radiors::RadioOption;
+
+fn plans() -> Vec<RadioOption<&'static str>> {
+    vec![
+        RadioOption::new("basic", "Basic").with_description("For individuals"),
+        RadioOption::new("pro", "Pro").with_description("For small teams"),
+        RadioOption::new("team", "Team").with_disabled(true),
+    ]
+}
+
+fn ranges() -> Vec<RadioOption<&'static str>> {
+    vec![
+        RadioOption::new("day", "Day"),
+        RadioOption::new("week", "Week"),
+        RadioOption::new("month", "Month"),
+    ]
+}
+
+/// Rewrites rendered HTML into one line per element or text that ignores what differs
+/// between the renderers without changing the meaning of the page: hydration markers,
+/// attribute order and the spelling of boolean attributes.
+fn normalize(html: &str) -> String {
+    let mut lines = Vec::new();
+    let mut depth = 0;
+    let mut text = String::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!") {
+            let end = comment.find('>').expect("unterminated marker");
+            rest = &comment[end + 1..];
+        } else if let Some(close) = rest.strip_prefix("</") {
+            flush(&mut text, &mut lines, depth);
+            let end = close.find('>').expect("unterminated closing tag");
+            depth -= 1;
+            rest = &close[end + 1..];
+        } else if let Some(open) = rest.strip_prefix('<') {
+            flush(&mut text, &mut lines, depth);
+            let end = tag_end(open);
+            let tag = open[..end].trim_end_matches('/');
+            let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+            lines.push(format!(
+                "{}<{}{}>",
+                "  ".repeat(depth),
+                name,
+                normalize_attributes(attributes)
+            ));
+            if !matches!(name, "input" | "img") {
+                depth += 1;
+            }
+            rest = &open[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            text.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    flush(&mut text, &mut lines, depth);
+    lines.join("\n")
+}
+
+/// The index of the `>` closing a tag, skipping quoted attribute values.
+fn tag_end(tag: &str) -> usize {
+    let mut quoted = false;
+    for (index, char) in tag.char_indices() {
+        match char {
+            '"' => quoted = !quoted,
+            '>' if !quoted => return index,
+            _ => {}
+        }
+    }
+    panic!("unterminated tag: {tag}")
+}
+
+fn flush(text: &mut String, lines: &mut Vec<String>, depth: usize) {
+    let trimmed = text.trim();
+    if !trimmed.is_empty() {
+        lines.push(format!("{}{trimmed}", "  ".repeat(depth)));
+    }
+    text.clear();
+}
+
+fn normalize_attributes(attributes: &str) -> String {
+    let mut parsed = Vec::new();
+    let mut rest = attributes.trim();
+    while !rest.is_empty() {
+        let end = rest.find([' ', '=']).unwrap_or(rest.len());
+        let name = &rest[..end];
+        rest = &rest[end..];
+        let value = if let Some(quoted) = rest.strip_prefix("=\"") {
+            let end = quoted.find('"').expect("unterminated attribute");
+            rest = &quoted[end + 1..];
+            Some(&quoted[..end])
+        } else if let Some(bare) = rest.strip_prefix('=') {
+            let end = bare.find(' ').unwrap_or(bare.len());
+            rest = &bare[end..];
+            Some(&bare[..end])
+        } else {
+            None
+        };
+        rest = rest.trim_start();
+        let value = match (name, value) {
+            ("checked" | "disabled" | "required", Some("false")) => continue,
+            ("checked" | "disabled" | "required", _) => String::new(),
+            (_, value) => value.unwrap_or_default().to_string(),
+        };
+        parsed.push((name.to_string(), value));
+    }
+    parsed.sort();
+    parsed
+        .iter()
+        .map(|(name, value)| format!(" {name}=\"{value}\""))
+        .collect()
+}
+
+/// Asserts that every adapter renders the same normalized markup.
+fn assert_conforms(yew: String, dioxus: String, leptos: String) {
+    let yew = normalize(&yew);
+    assert_eq!(yew, normalize(&dioxus), "Yew and Dioxus differ");
+    assert_eq!(yew, normalize(&leptos), "Yew and Leptos differ");
+}
+
+mod yew {
+    use super::{plans, ranges};
+    use radiors::yew::{Group, IdScope, Radio};
+    use radiors::{Orientation, Size, Type, Variant};
+    use yew::prelude::*;
+    use yew::LocalServerRenderer;
+
+    #[function_component(Plans)]
+    fn plan_picker() -> Html {
+        html! {
+            <Group<&'static str>
+                selected="pro"
+                orientation={Orientation::Vertical}
+                aria_label="Plan"
+                required=true
+                name="plan"
+            >
+                <Radio<&'static str> value="basic" label="Basic" description="For individuals" />
+                <Radio<&'static str>
+                    value="pro"
+                    label="Pro"
+                    r#type={Type::Primary}
+                    size={Size::Medium}
+                />
+                <Radio<&'static str> value="team" label="Team" disabled=true />
+            </Group<&'static str>>
+        }
+    }
+
+    #[function_component(Cards)]
+    fn cards() -> Html {
+        html! {
+            <Group<&'static str>
+                selected="pro"
+                options={plans()}
+                variant={Variant::Card}
+                check_mark=true
+                aria_labelledby="plan-heading"
+            />
+        }
+    }
+
+    #[function_component(Segments)]
+    fn segments() -> Html {
+        html! {
+            <Group<&'static str> selected="week" options={ranges()} variant={Variant::Segmented} />
+        }
+    }
+
+    #[function_component(Unselected)]
+    fn unselected() -> Html {
+        html! {
+            <Group<&'static str> selected={None} default_selected="week" options={ranges()} />
+        }
+    }
+
+    #[function_component(Defaults)]
+    fn defaults() -> Html {
+        html! { <Group<&'static str> options={ranges()} /> }
+    }
+
+    /// Renders `C` in its own `IdScope`, as a server does for each page.
+    #[function_component(Scoped)]
+    fn scoped<C: BaseComponent<Properties = ()>>() -> Html {
+        html! { <IdScope><C /></IdScope> }
+    }
+
+    fn render<C: BaseComponent<Properties = ()>>() -> String {
+        futures::executor::block_on(
+            LocalServerRenderer::<Scoped<C>>::new()
+                .hydratable(false)
+                .render(),
+        )
+    }
+
+    pub fn plan_group() -> String {
+        render::<Plans>()
+    }
+
+    pub fn card_group() -> String {
+        render::<Cards>()
+    }
+
+    pub fn segmented_group() -> String {
+        render::<Segments>()
+    }
+
+    pub fn default_group() -> String {
+        render::<Defaults>()
+    }
+
+    pub fn unselected_group() -> String {
+        render::<Unselected>()
+    }
+}
+
+mod dioxus {
+    use super::{plans, ranges};
+    use dioxus::prelude::*;
+    use radiors::dioxus::{Group, Radio};
+    use radiors::{Orientation, Size, Type, Variant};
+
+    fn plan_picker() -> Element {
+        rsx! {
+            Group::<&str> {
+                selected: "pro",
+                orientation: Orientation::Vertical,
+                aria_label: "Plan",
+                required: true,
+                name: "plan",
+                Radio { value: "basic", label: "Basic", description: "For individuals" }
+                Radio {
+                    value: "pro",
+                    label: "Pro",
+                    r#type: Type::Primary,
+                    size: Size::Medium,
+                }
+                Radio { value: "team", label: "Team", disabled: true }
+            }
+        }
+    }
+
+    fn cards() -> Element {
+        rsx! {
+            Group::<&str> {
+                selected: "pro",
+                options: plans(),
+                variant: Variant::Card,
+                check_mark: true,
+                aria_labelledby: "plan-heading",
+            }
+        }
+    }
+
+    fn segments() -> Element {
+        rsx! {
+            Group::<&str> { selected: "week", options: ranges(), variant: Variant::Segmented }
+        }
+    }
+
+    fn unselected() -> Element {
+        rsx! {
+            Group::<&str> { selected: None, default_selected: "week", options: ranges() }
+        }
+    }
+
+    fn defaults() -> Element {
+        rsx! {
+            Group::<&str> { options: ranges() }
+        }
+    }
+
+    fn render(app: fn() -> Element) -> String {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dioxus::ssr::render(&dom)
+    }
+
+    pub fn plan_group() -> String {
+        render(plan_picker)
+    }
+
+    pub fn card_group() -> String {
+        render(cards)
+    }
+
+    pub fn segmented_group() -> String {
+        render(segments)
+    }
+
+    pub fn default_group() -> String {
+        render(defaults)
+    }
+
+    pub fn unselected_group() -> String {
+        render(unselected)
+    }
+}
+
+mod leptos {
+    use super::{plans, ranges};
+    use leptos::prelude::*;
+    use leptos::task::Executor;
+    use radiors::leptos::{Group, IdScope, Radio};
+    use radiors::{Orientation, Size, Type, Variant};
+
+    fn render<V: IntoView + 'static>(view: impl FnOnce() -> V + Send + 'static) -> String {
+        // With `hydrate` enabled as well, effects are spawned and need an executor.
+        let _ = Executor::init_futures_executor();
+        Owner::new().with(|| view! { <IdScope>{view()}</IdScope> }.to_html())
+    }
+
+    pub fn plan_group() -> String {
+        render(|| {
+            view! {
+                <Group
+                    selected="pro"
+                    orientation=Orientation::Vertical
+                    aria_label="Plan"
+                    required=true
+                    name="plan"
+                >
+                    <Radio value="basic" label="Basic" description="For individuals" />
+                    <Radio value="pro" label="Pro" r#type=Type::Primary size=Size::Medium />
+                    <Radio value="team" label="Team" disabled=true />
+                </Group>
+            }
+        })
+    }
+
+    pub fn card_group() -> String {
+        render(|| {
+            view! {
+                <Group
+                    selected="pro"
+                    options=plans()
+                    variant=Variant::Card
+                    check_mark=true
+                    aria_labelledby="plan-heading"
+                />
+            }
+        })
+    }
+
+    pub fn segmented_group() -> String {
+        render(|| {
+            view! { <Group selected="week" options=ranges() variant=Variant::Segmented /> }
+        })
+    }
+
+    pub fn default_group() -> String {
+        render(|| view! { <Group options=ranges() /> })
+    }
+
+    pub fn unselected_group() -> String {
+        render(|| view! { <Group selected=None default_selected="week" options=ranges() /> })
+    }
+}
+
+#[test]
+fn plan_groups_conform() {
+    assert_conforms(
+        yew::plan_group(),
+        dioxus::plan_group(),
+        leptos::plan_group(),
+    );
+}
+
+#[test]
+fn card_groups_conform() {
+    assert_conforms(
+        yew::card_group(),
+        dioxus::card_group(),
+        leptos::card_group(),
+    );
+}
+
+#[test]
+fn segmented_groups_conform() {
+    assert_conforms(
+        yew::segmented_group(),
+        dioxus::segmented_group(),
+        leptos::segmented_group(),
+    );
+}
+
+#[test]
+fn default_groups_conform() {
+    assert_conforms(
+        yew::default_group(),
+        dioxus::default_group(),
+        leptos::default_group(),
+    );
+}
+
+#[test]
+fn selected_none_keeps_default_selected_everywhere() {
+    let yew = yew::unselected_group();
+    assert!(yew.contains(r#"value="week" checked"#), "{yew}");
+    assert_conforms(yew, dioxus::unselected_group(), leptos::unselected_group());
+}
+
+#[test]
+fn normalize_ignores_renderer_spelling() {
+    assert_eq!(
+        normalize(r#"<div class="a b" style="color: red;"><!><input checked=true/>A<!>B</div>"#),
+        normalize(r#"<div style="color: red;" class="a b"><input checked>AB</div>"#),
+    );
+}
+
+#[test]
+fn normalize_keeps_class_and_style_values() {
+    assert_ne!(
+        normalize(r#"<div class=""></div>"#),
+        normalize("<div></div>")
+    );
+    assert_ne!(
+        normalize(r#"<div class="b a"></div>"#),
+        normalize(r#"<div class="a b"></div>"#)
+    );
+    assert_ne!(
+        normalize(r#"<div style="color: red;;"></div>"#),
+        normalize(r#"<div style="color: red;"></div>"#),
+    );
+}
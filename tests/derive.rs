@@ -0,0 +1,62 @@
+#![cfg(feature = "derive")]
+
+use radiors::{RadioOption, RadioOptions, RadioValue};
+
+#[derive(Clone, Copy, Debug, PartialEq, RadioOptions)]
+enum Shipping {
+    Standard,
+    #[radio(
+        value = "express",
+        label = "Express",
+        description = "Next business day"
+    )]
+    Express,
+    #[radio(value = "drone", label = "Drone", src = "drone.png", disabled)]
+    Drone,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, RadioOptions)]
+#[radio(no_display)]
+enum Size {
+    #[radio(value = "s")]
+    Small,
+    #[radio(value = "l")]
+    Large,
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_value())
+    }
+}
+
+#[test]
+fn options_follow_declaration_order() {
+    assert_eq!(
+        Shipping::options(),
+        vec![
+            RadioOption::new(Shipping::Standard, "Standard"),
+            RadioOption::new(Shipping::Express, "Express").with_description("Next business day"),
+            RadioOption::new(Shipping::Drone, "Drone")
+                .with_src("drone.png")
+                .with_disabled(true),
+        ]
+    );
+}
+
+#[test]
+fn values_round_trip() {
+    for option in Shipping::options() {
+        let value = option.value.to_value();
+        assert_eq!(value, option.value.as_value());
+        assert_eq!(Shipping::from_value(&value), Some(option.value));
+    }
+    assert_eq!(Shipping::Standard.to_string(), "Standard");
+    assert_eq!(Shipping::from_value("bike"), None);
+}
+
+#[test]
+fn display_can_be_implemented_by_hand() {
+    assert_eq!(Size::Large.to_string(), "l");
+    assert_eq!(Size::from_value(&Size::Small.to_value()), Some(Size::Small));
+}
@@ -0,0 +1,31 @@
+use radiors::{Align, Justify, Orientation, Size};
+
+#[test]
+fn gap_follows_the_spacing_scale() {
+    assert_eq!(
+        Size::Medium.to_gap(),
+        "gap: var(--radiors-spacing-medium, 10px);"
+    );
+    assert_eq!(
+        Size::XXLarge.to_gap(),
+        "gap: var(--radiors-spacing-xxlarge, 30px);"
+    );
+    assert_eq!(Size::Custom("2rem").to_gap(), "gap: 2rem;");
+}
+
+#[test]
+fn alignment_and_justification_map_to_flex_properties() {
+    assert_eq!(Align::Center.to_style(), "align-items: center;");
+    assert_eq!(Align::Baseline.to_style(), "align-items: baseline;");
+    assert_eq!(Justify::End.to_style(), "justify-content: end;");
+    assert_eq!(
+        Justify::SpaceBetween.to_style(),
+        "justify-content: space-between;"
+    );
+}
+
+#[test]
+fn orientations_keep_their_default_gap() {
+    assert!(Orientation::Horizontal.to_style().contains("gap: 16px;"));
+    assert!(Orientation::Vertical.to_style().contains("gap: 16px;"));
+}
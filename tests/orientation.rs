@@ -0,0 +1,50 @@
+use radiors::{Grid, Orientation};
+
+#[test]
+fn grid_uses_equal_columns() {
+    let style = Orientation::grid(3).to_style();
+    assert!(style.contains("display: grid;"));
+    assert!(style.contains("grid-template-columns: repeat(3, minmax(0, 1fr));"));
+    assert!(style.contains("gap: 16px;"));
+}
+
+#[test]
+fn grid_with_min_column_width_wraps() {
+    let grid = Grid::new(3).with_gap("1rem").with_min_column_width("200px");
+    assert_eq!(
+        grid.to_style(),
+        "display: grid; grid-template-columns: repeat(auto-fill, minmax(max(200px, calc((100% - 2 * 1rem) / 3)), 1fr)); gap: 1rem;"
+    );
+}
+
+#[test]
+fn grid_has_at_least_one_column() {
+    assert_eq!(Grid::new(0).columns, 1);
+}
+
+#[test]
+fn responsive_picks_the_widest_matching_breakpoint() {
+    let orientation = Orientation::responsive([
+        (1024, Orientation::grid(4)),
+        (0, Orientation::Vertical),
+        (640, Orientation::grid(2)),
+    ]);
+    assert!(orientation.is_responsive());
+    assert_eq!(orientation.resolve(Some(0)), &Orientation::Vertical);
+    assert_eq!(orientation.resolve(Some(639)), &Orientation::Vertical);
+    assert_eq!(orientation.resolve(Some(640)), &Orientation::grid(2));
+    assert_eq!(orientation.resolve(Some(2000)), &Orientation::grid(4));
+}
+
+#[test]
+fn responsive_falls_back_to_the_narrowest_layout() {
+    let orientation =
+        Orientation::responsive([(480, Orientation::Horizontal), (960, Orientation::grid(3))]);
+    assert_eq!(orientation.resolve(None), &Orientation::Horizontal);
+    assert_eq!(orientation.resolve(Some(100)), &Orientation::Horizontal);
+    assert_eq!(orientation.to_style(), Orientation::Horizontal.to_style());
+    assert_eq!(
+        Orientation::responsive([]).resolve(None),
+        &Orientation::Vertical
+    );
+}
@@ -0,0 +1,337 @@
+#![cfg(feature = "ssr")]
+#![allow(dead_code)]
+
+use radiors::{Orientation, Size, Type, Variant};
+
+/// Asserts a snapshot of rendered markup.
+///
+/// Stylesheet mode renders classes instead of inline styles, so it keeps its own set of
+/// snapshots with a `stylesheet` suffix.
+macro_rules! assert_markup_snapshot {
+    ($markup:expr) => {{
+        let mut settings = insta::Settings::clone_current();
+        if cfg!(feature = "stylesheet") {
+            settings.set_snapshot_suffix("stylesheet");
+        }
+        settings.bind(|| insta::assert_snapshot!($markup));
+    }};
+}
+
+/// One rendered group: a first radio button styled by the case and a plain second one.
+struct Case {
+    name: String,
+    variant: Variant,
+    orientation: Orientation,
+    size: Size,
+    r#type: Type,
+    selected: bool,
+    disabled: bool,
+}
+
+impl Case {
+    fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            variant: Variant::default(),
+            orientation: Orientation::Horizontal,
+            size: Size::default(),
+            r#type: Type::default(),
+            selected: false,
+            disabled: false,
+        }
+    }
+}
+
+fn sizes() -> Vec<Case> {
+    [
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Large,
+        Size::XLarge,
+        Size::XXLarge,
+        Size::Custom("3rem"),
+    ]
+    .into_iter()
+    .map(|size| Case {
+        size: size.clone(),
+        ..Case::new(format!("{size:?}"))
+    })
+    .collect()
+}
+
+fn types() -> Vec<Case> {
+    [
+        Type::Primary,
+        Type::Secondary,
+        Type::Success,
+        Type::Info,
+        Type::Warning,
+        Type::Danger,
+        Type::None,
+        Type::Custom("teal"),
+    ]
+    .into_iter()
+    .map(|r#type| Case {
+        r#type: r#type.clone(),
+        ..Case::new(format!("{type:?}"))
+    })
+    .collect()
+}
+
+fn orientations() -> Vec<Case> {
+    [
+        Orientation::Horizontal,
+        Orientation::Vertical,
+        Orientation::grid(2),
+        Orientation::responsive([(0, Orientation::Vertical), (600, Orientation::Horizontal)]),
+    ]
+    .into_iter()
+    .map(|orientation| Case {
+        orientation: orientation.clone(),
+        ..Case::new(format!("{orientation:?}"))
+    })
+    .collect()
+}
+
+fn states() -> Vec<Case> {
+    [(false, false), (true, false), (false, true), (true, true)]
+        .into_iter()
+        .map(|(selected, disabled)| Case {
+            selected,
+            disabled,
+            ..Case::new(format!("selected: {selected}, disabled: {disabled}"))
+        })
+        .collect()
+}
+
+/// Every variant in every state, laid out in a grid.
+fn variants() -> Vec<Case> {
+    [Variant::Default, Variant::Card, Variant::Segmented]
+        .into_iter()
+        .flat_map(|variant| {
+            states().into_iter().map(move |case| Case {
+                name: format!("{variant:?}, {}", case.name),
+                variant,
+                orientation: Orientation::grid(2),
+                ..case
+            })
+        })
+        .collect()
+}
+
+/// Renders every case with `render`, each preceded by its name.
+fn snapshot(cases: Vec<Case>, render: impl Fn(&Case) -> String) -> String {
+    cases
+        .iter()
+        .map(|case| format!("<!-- {} -->\n{}\n", case.name, render(case)))
+        .collect()
+}
+
+#[cfg(feature = "yew")]
+mod yew {
+    use super::{orientations, sizes, snapshot, states, types, variants, Case};
+    use radiors::yew::{Group, IdScope, Radio};
+    use yew::prelude::*;
+    use yew::LocalServerRenderer;
+
+    #[derive(Properties, PartialEq)]
+    struct FixtureProps {
+        variant: radiors::Variant,
+        orientation: radiors::Orientation,
+        size: radiors::Size,
+        r#type: radiors::Type,
+        selected: bool,
+        disabled: bool,
+    }
+
+    #[function_component(Fixture)]
+    fn fixture(props: &FixtureProps) -> Html {
+        html! {
+            <IdScope>
+                <Group
+                    selected={props.selected.then(|| "a".to_string())}
+                    variant={props.variant}
+                    orientation={props.orientation.clone()}
+                >
+                    <Radio
+                        value="a"
+                        label="A"
+                        size={props.size.clone()}
+                        r#type={props.r#type.clone()}
+                        disabled={props.disabled}
+                    />
+                    <Radio value="b" label="B" />
+                </Group>
+            </IdScope>
+        }
+    }
+
+    fn render(case: &Case) -> String {
+        let props = FixtureProps {
+            variant: case.variant,
+            orientation: case.orientation.clone(),
+            size: case.size.clone(),
+            r#type: case.r#type.clone(),
+            selected: case.selected,
+            disabled: case.disabled,
+        };
+        futures::executor::block_on(
+            LocalServerRenderer::<Fixture>::with_props(props)
+                .hydratable(false)
+                .render(),
+        )
+    }
+
+    #[test]
+    fn sizes_match_snapshot() {
+        assert_markup_snapshot!(snapshot(sizes(), render));
+    }
+
+    #[test]
+    fn types_match_snapshot() {
+        assert_markup_snapshot!(snapshot(types(), render));
+    }
+
+    #[test]
+    fn orientations_match_snapshot() {
+        assert_markup_snapshot!(snapshot(orientations(), render));
+    }
+
+    #[test]
+    fn states_match_snapshot() {
+        assert_markup_snapshot!(snapshot(states(), render));
+    }
+
+    #[test]
+    fn variants_match_snapshot() {
+        assert_markup_snapshot!(snapshot(variants(), render));
+    }
+}
+
+#[cfg(feature = "dio")]
+mod dioxus {
+    use super::{orientations, sizes, snapshot, states, types, variants, Case};
+    use dioxus::prelude::*;
+    use radiors::dioxus::{Group, Radio};
+
+    #[derive(Props, Clone, PartialEq)]
+    struct FixtureProps {
+        variant: radiors::Variant,
+        orientation: radiors::Orientation,
+        size: radiors::Size,
+        r#type: radiors::Type,
+        selected: bool,
+        disabled: bool,
+    }
+
+    #[component]
+    fn Fixture(props: FixtureProps) -> Element {
+        rsx! {
+            Group::<&str> {
+                selected: props.selected.then_some("a"),
+                variant: props.variant,
+                orientation: props.orientation,
+                Radio {
+                    value: "a",
+                    label: "A",
+                    size: props.size,
+                    r#type: props.r#type,
+                    disabled: props.disabled,
+                }
+                Radio { value: "b", label: "B" }
+            }
+        }
+    }
+
+    fn render(case: &Case) -> String {
+        let props = FixtureProps {
+            variant: case.variant,
+            orientation: case.orientation.clone(),
+            size: case.size.clone(),
+            r#type: case.r#type.clone(),
+            selected: case.selected,
+            disabled: case.disabled,
+        };
+        let mut dom = VirtualDom::new_with_props(Fixture, props);
+        dom.rebuild_in_place();
+        dioxus::ssr::render(&dom)
+    }
+
+    #[test]
+    fn sizes_match_snapshot() {
+        assert_markup_snapshot!(snapshot(sizes(), render));
+    }
+
+    #[test]
+    fn types_match_snapshot() {
+        assert_markup_snapshot!(snapshot(types(), render));
+    }
+
+    #[test]
+    fn orientations_match_snapshot() {
+        assert_markup_snapshot!(snapshot(orientations(), render));
+    }
+
+    #[test]
+    fn states_match_snapshot() {
+        assert_markup_snapshot!(snapshot(states(), render));
+    }
+
+    #[test]
+    fn variants_match_snapshot() {
+        assert_markup_snapshot!(snapshot(variants(), render));
+    }
+}
+
+#[cfg(feature = "lep")]
+mod leptos {
+    use super::{orientations, sizes, snapshot, states, types, variants, Case};
+    use leptos::prelude::*;
+    use leptos::task::Executor;
+    use radiors::leptos::{Group, IdScope, Radio};
+
+    fn render(case: &Case) -> String {
+        let (size, r#type, disabled) = (case.size.clone(), case.r#type.clone(), case.disabled);
+        let (selected, orientation) = (case.selected.then_some("a"), case.orientation.clone());
+        let variant = case.variant;
+        // With `hydrate` enabled as well, effects are spawned and need an executor.
+        let _ = Executor::init_futures_executor();
+        Owner::new().with(|| {
+            view! {
+                <IdScope>
+                    <Group selected=selected variant=variant orientation=orientation>
+                        <Radio value="a" label="A" size=size r#type=r#type disabled=disabled />
+                        <Radio value="b" label="B" />
+                    </Group>
+                </IdScope>
+            }
+            .to_html()
+        })
+    }
+
+    #[test]
+    fn sizes_match_snapshot() {
+        assert_markup_snapshot!(snapshot(sizes(), render));
+    }
+
+    #[test]
+    fn types_match_snapshot() {
+        assert_markup_snapshot!(snapshot(types(), render));
+    }
+
+    #[test]
+    fn orientations_match_snapshot() {
+        assert_markup_snapshot!(snapshot(orientations(), render));
+    }
+
+    #[test]
+    fn states_match_snapshot() {
+        assert_markup_snapshot!(snapshot(states(), render));
+    }
+
+    #[test]
+    fn variants_match_snapshot() {
+        assert_markup_snapshot!(snapshot(variants(), render));
+    }
+}
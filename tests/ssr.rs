@@ -0,0 +1,375 @@
+#![cfg(feature = "ssr")]
+
+/// Asserts that a rendered group carries its generated name and selection.
+#[allow(dead_code)]
+fn assert_rendered_group(html: &str) {
+    assert!(html.contains(r#"role="radiogroup""#), "{html}");
+    assert_eq!(html.matches(r#"name="radiors-0""#).count(), 2, "{html}");
+    assert!(html.contains(r#"id="radiors-description-"#), "{html}");
+    assert!(html.contains("checked"), "{html}");
+}
+
+/// Asserts that the generated names and IDs in `html` are numbered `0..count` without gaps,
+/// i.e. that no component took a number for something it did not render.
+#[allow(dead_code)]
+fn assert_ids_without_gaps(html: &str, count: usize) {
+    let mut numbers = html
+        .split(r#""radiors-"#)
+        .skip(1)
+        .filter_map(|id| {
+            let id = id.strip_prefix("description-").unwrap_or(id);
+            id[..id.find('"')?].parse::<usize>().ok()
+        })
+        .collect::<Vec<_>>();
+    numbers.sort_unstable();
+    numbers.dedup();
+    assert_eq!(numbers, (0..count).collect::<Vec<_>>(), "{html}");
+}
+
+/// The values of the radio inputs rendered as checked.
+#[allow(dead_code)]
+fn checked_values(html: &str) -> Vec<&str> {
+    html.split("<input")
+        .skip(1)
+        .map(|input| &input[..input.find('>').unwrap_or(input.len())])
+        .filter(|input| input.contains("checked") && !input.contains(r#"checked="false""#))
+        .filter_map(|input| {
+            let value = &input[input.find(r#"value=""#)? + 7..];
+            Some(&value[..value.find('"')?])
+        })
+        .collect()
+}
+
+#[cfg(feature = "yew")]
+mod yew {
+    use radiors::yew::{Group, IdScope, Radio};
+    use yew::prelude::*;
+    use yew::LocalServerRenderer;
+
+    #[function_component(App)]
+    fn app() -> Html {
+        html! {
+            <Group selected={"mac".to_string()}>
+                <Radio value="mac" label="macOS" description="Sonoma" />
+                <Radio value="linux" label="Linux" />
+            </Group>
+        }
+    }
+
+    #[function_component(Uncontrolled)]
+    fn uncontrolled() -> Html {
+        html! {
+            <Group default_selected="linux">
+                <Radio value="mac" label="macOS" />
+                <Radio value="linux" label="Linux" />
+            </Group>
+        }
+    }
+
+    #[function_component(Controlled)]
+    fn controlled() -> Html {
+        html! {
+            <Group selected="mac" default_selected="linux">
+                <Radio value="mac" label="macOS" />
+                <Radio value="linux" label="Linux" />
+            </Group>
+        }
+    }
+
+    #[function_component(Bound)]
+    fn bound() -> Html {
+        let os = use_state(|| "linux".to_string());
+        html! {
+            <Group selected="mac" bind={os}>
+                <Radio value="mac" label="macOS" />
+                <Radio value="linux" label="Linux" />
+            </Group>
+        }
+    }
+
+    #[function_component(Described)]
+    fn described() -> Html {
+        html! {
+            <Group>
+                <Radio value="mac" label="macOS" description="Sonoma" />
+                <Radio value="linux" label="Linux" />
+                <Radio value="windows" label="Windows" description="11" />
+            </Group>
+        }
+    }
+
+    /// Renders `C` in its own `IdScope`, as a server does for each page.
+    #[function_component(Scoped)]
+    fn scoped<C: BaseComponent<Properties = ()>>() -> Html {
+        html! { <IdScope><C /></IdScope> }
+    }
+
+    fn render_app<C: BaseComponent<Properties = ()>>() -> String {
+        futures::executor::block_on(LocalServerRenderer::<Scoped<C>>::new().render())
+    }
+
+    fn render() -> String {
+        render_app::<App>()
+    }
+
+    #[test]
+    fn renders_group_to_html() {
+        super::assert_rendered_group(&render());
+    }
+
+    #[test]
+    fn generated_names_are_deterministic() {
+        assert_eq!(render(), render());
+    }
+
+    #[test]
+    fn default_selected_is_checked() {
+        assert_eq!(
+            super::checked_values(&render_app::<Uncontrolled>()),
+            ["linux"]
+        );
+    }
+
+    #[test]
+    fn selected_overrides_default_selected() {
+        assert_eq!(super::checked_values(&render_app::<Controlled>()), ["mac"]);
+    }
+
+    #[test]
+    fn bind_overrides_selected() {
+        assert_eq!(super::checked_values(&render_app::<Bound>()), ["linux"]);
+    }
+}
+
+#[cfg(feature = "dio")]
+mod dioxus {
+    use dioxus::prelude::*;
+    use radiors::dioxus::{Group, Radio};
+
+    fn app() -> Element {
+        rsx! {
+            Group::<&str> {
+                selected: "mac",
+                Radio { value: "mac", label: "macOS", description: "Sonoma" }
+                Radio { value: "linux", label: "Linux" }
+            }
+        }
+    }
+
+    fn uncontrolled() -> Element {
+        rsx! {
+            Group::<&str> {
+                default_selected: "linux",
+                Radio { value: "mac", label: "macOS" }
+                Radio { value: "linux", label: "Linux" }
+            }
+        }
+    }
+
+    fn controlled() -> Element {
+        rsx! {
+            Group::<&str> {
+                selected: "mac",
+                default_selected: "linux",
+                Radio { value: "mac", label: "macOS" }
+                Radio { value: "linux", label: "Linux" }
+            }
+        }
+    }
+
+    fn bound() -> Element {
+        let os = use_signal(|| "linux");
+        rsx! {
+            Group {
+                selected: "mac",
+                bind: os,
+                Radio { value: "mac", label: "macOS" }
+                Radio { value: "linux", label: "Linux" }
+            }
+        }
+    }
+
+    thread_local! {
+        static CLEARED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    }
+
+    fn clearable() -> Element {
+        let selected = (!CLEARED.get()).then_some("mac");
+        rsx! {
+            Group::<&str> {
+                selected,
+                default_selected: "linux",
+                Radio { value: "mac", label: "macOS" }
+                Radio { value: "linux", label: "Linux" }
+            }
+        }
+    }
+
+    fn described() -> Element {
+        rsx! {
+            Group::<&str> {
+                Radio { value: "mac", label: "macOS", description: "Sonoma" }
+                Radio { value: "linux", label: "Linux" }
+                Radio { value: "windows", label: "Windows", description: "11" }
+            }
+        }
+    }
+
+    fn render_app(app: fn() -> Element) -> String {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dioxus::ssr::render(&dom)
+    }
+
+    fn render() -> String {
+        render_app(app)
+    }
+
+    #[test]
+    fn renders_group_to_html() {
+        super::assert_rendered_group(&render());
+    }
+
+    #[test]
+    fn generated_names_are_deterministic() {
+        assert_eq!(render(), render());
+    }
+
+    #[test]
+    fn only_rendered_ids_are_generated() {
+        super::assert_ids_without_gaps(&render_app(described), 3);
+    }
+
+    #[test]
+    fn default_selected_is_checked() {
+        assert_eq!(super::checked_values(&render_app(uncontrolled)), ["linux"]);
+    }
+
+    #[test]
+    fn selected_overrides_default_selected() {
+        assert_eq!(super::checked_values(&render_app(controlled)), ["mac"]);
+    }
+
+    #[test]
+    fn bind_overrides_selected() {
+        assert_eq!(super::checked_values(&render_app(bound)), ["linux"]);
+    }
+
+    #[test]
+    fn clearing_selected_clears_the_selection() {
+        CLEARED.set(false);
+        let mut dom = VirtualDom::new(clearable);
+        dom.rebuild_in_place();
+        assert_eq!(super::checked_values(&dioxus::ssr::render(&dom)), ["mac"]);
+
+        CLEARED.set(true);
+        dom.mark_dirty(ScopeId::APP);
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        assert!(super::checked_values(&dioxus::ssr::render(&dom)).is_empty());
+    }
+}
+
+#[cfg(feature = "lep")]
+mod leptos {
+    use leptos::prelude::*;
+    use leptos::task::Executor;
+    use radiors::leptos::{Group, IdScope, Radio};
+
+    fn render_view<V: IntoView + 'static>(view: impl FnOnce() -> V + Send + 'static) -> String {
+        // With `hydrate` enabled as well, effects are spawned and need an executor.
+        let _ = Executor::init_futures_executor();
+        Owner::new().with(|| view! { <IdScope>{view()}</IdScope> }.to_html())
+    }
+
+    fn render() -> String {
+        render_view(|| {
+            view! {
+                <Group selected="mac">
+                    <Radio value="mac" label="macOS" description="Sonoma" />
+                    <Radio value="linux" label="Linux" />
+                </Group>
+            }
+        })
+    }
+
+    #[test]
+    fn renders_group_to_html() {
+        super::assert_rendered_group(&render());
+    }
+
+    #[test]
+    fn generated_names_are_deterministic() {
+        assert_eq!(render(), render());
+    }
+
+    #[test]
+    fn only_rendered_ids_are_generated() {
+        // Nothing selects a value, so the value type cannot be inferred.
+        type Os = &'static str;
+        let html = render_view(|| {
+            view! {
+                <Group<Os>>
+                    <Radio value="mac" label="macOS" description="Sonoma" />
+                    <Radio value="linux" label="Linux" />
+                    <Radio value="windows" label="Windows" description="11" />
+                </Group<Os>>
+            }
+        });
+        super::assert_ids_without_gaps(&html, 3);
+    }
+
+    #[test]
+    fn default_selected_is_checked() {
+        let html = render_view(|| {
+            view! {
+                <Group default_selected="linux">
+                    <Radio value="mac" label="macOS" />
+                    <Radio value="linux" label="Linux" />
+                </Group>
+            }
+        });
+        assert_eq!(super::checked_values(&html), ["linux"]);
+    }
+
+    #[test]
+    fn selected_overrides_default_selected() {
+        let html = render_view(|| {
+            view! {
+                <Group selected="mac" default_selected="linux">
+                    <Radio value="mac" label="macOS" />
+                    <Radio value="linux" label="Linux" />
+                </Group>
+            }
+        });
+        assert_eq!(super::checked_values(&html), ["mac"]);
+    }
+
+    #[test]
+    fn bind_overrides_selected() {
+        let html = render_view(|| {
+            let os = RwSignal::new("linux");
+            view! {
+                <Group selected="mac" bind=os>
+                    <Radio value="mac" label="macOS" />
+                    <Radio value="linux" label="Linux" />
+                </Group>
+            }
+        });
+        assert_eq!(super::checked_values(&html), ["linux"]);
+    }
+
+    #[test]
+    fn selected_none_keeps_default_selected() {
+        let html = render_view(|| {
+            let os = RwSignal::new(None::<&str>);
+            view! {
+                <Group selected=os default_selected="linux">
+                    <Radio value="mac" label="macOS" />
+                    <Radio value="linux" label="Linux" />
+                </Group>
+            }
+        });
+        assert_eq!(super::checked_values(&html), ["linux"]);
+    }
+}
@@ -0,0 +1,161 @@
+use radiors::RadioGroupState;
+
+fn os() -> RadioGroupState<&'static str> {
+    RadioGroupState::new(["mac", "windows", "linux"])
+}
+
+#[test]
+fn select_reports_changes_only() {
+    let mut state = os();
+    assert!(state.select(&"mac"));
+    assert!(!state.select(&"mac"));
+    assert!(state.select(&"linux"));
+    assert_eq!(state.selected(), Some(&"linux"));
+    assert_eq!(state.focused(), Some(&"linux"));
+}
+
+#[test]
+fn select_ignores_disabled_and_unknown_options() {
+    let mut state = os().with_disabled("windows");
+    assert!(!state.select(&"windows"));
+    assert!(!state.select(&"bsd"));
+    assert_eq!(state.selected(), None);
+}
+
+#[test]
+fn navigation_wraps_and_skips_disabled() {
+    let mut state = os().with_selected("linux").with_disabled("mac");
+    assert!(state.select_next());
+    assert_eq!(state.selected(), Some(&"windows"));
+    assert!(state.select_prev());
+    assert_eq!(state.selected(), Some(&"linux"));
+}
+
+#[test]
+fn navigation_without_selection_starts_at_the_ends() {
+    let mut state = os();
+    assert!(state.focus_next());
+    assert_eq!(state.focused(), Some(&"mac"));
+
+    let mut state = os();
+    assert!(state.focus_prev());
+    assert_eq!(state.focused(), Some(&"linux"));
+}
+
+#[test]
+fn home_and_end_select_first_and_last_enabled() {
+    let mut state = os().with_disabled("linux");
+    assert!(state.select_last());
+    assert_eq!(state.selected(), Some(&"windows"));
+    assert!(state.select_first());
+    assert_eq!(state.selected(), Some(&"mac"));
+}
+
+#[test]
+fn all_disabled_never_moves() {
+    let mut state = os()
+        .with_disabled("mac")
+        .with_disabled("windows")
+        .with_disabled("linux");
+    assert!(!state.focus_next());
+    assert!(!state.select_first());
+    assert_eq!(state.focused(), None);
+}
+
+#[test]
+fn clear_and_reset() {
+    let mut state = os().with_selected("mac");
+    assert!(state.select(&"windows"));
+    assert!(state.clear());
+    assert!(!state.clear());
+    assert!(state.reset());
+    assert_eq!(state.selected(), Some(&"mac"));
+    assert!(!state.reset());
+}
+
+#[test]
+fn reset_to_no_selection() {
+    // The change has no value to emit, so only uncontrolled groups follow it, without `onchange`.
+    let mut state = os();
+    assert!(state.select(&"linux"));
+    assert!(state.reset());
+    assert_eq!(state.selected(), None);
+    assert!(!state.reset());
+}
+
+#[test]
+fn required_validity() {
+    let mut state = os().with_required(true);
+    assert!(!state.is_valid());
+    state.select(&"mac");
+    assert!(state.is_valid());
+    state.set_required(false);
+    state.clear();
+    assert!(state.is_valid());
+}
+
+#[test]
+fn register_and_unregister() {
+    let mut state = RadioGroupState::default();
+    state.register("a", false);
+    state.register("b", true);
+    state.register("a", false);
+    assert_eq!(state.options(), &["a", "b"]);
+    assert!(state.is_disabled(&"b"));
+
+    state.register("b", false);
+    assert!(!state.is_disabled(&"b"));
+
+    state.select(&"b");
+    state.unregister(&"b");
+    assert_eq!(state.options(), &["a"]);
+    assert_eq!(state.focused(), None);
+    assert_eq!(state.selected(), Some(&"b"));
+}
+
+#[test]
+fn tab_stop_prefers_enabled_selection() {
+    let state = os().with_selected("windows");
+    assert_eq!(state.tab_stop(), Some(&"windows"));
+
+    let state = os().with_selected("windows").with_disabled("windows");
+    assert_eq!(state.tab_stop(), Some(&"mac"));
+
+    let state = os().with_disabled("mac");
+    assert!(state.is_tab_stop(&"windows"));
+}
+
+#[test]
+fn unregistered_selection_holds_the_tab_stop() {
+    let mut state = RadioGroupState::default().with_selected("linux");
+    state.register("mac", false);
+    assert!(state.is_tab_stop(&"mac"));
+    assert!(!state.is_tab_stop_while_registering(&"mac"));
+
+    state.register("linux", false);
+    assert!(state.is_tab_stop_while_registering(&"linux"));
+    assert!(!state.is_tab_stop_while_registering(&"mac"));
+}
+
+#[test]
+fn keys_drive_selection() {
+    let mut state = os().with_selected("mac");
+    assert_eq!(state.handle_key("ArrowRight"), Some(true));
+    assert_eq!(state.selected(), Some(&"windows"));
+    assert_eq!(state.handle_key("ArrowUp"), Some(true));
+    assert_eq!(state.selected(), Some(&"mac"));
+    assert_eq!(state.handle_key("ArrowLeft"), Some(true));
+    assert_eq!(state.selected(), Some(&"linux"));
+    assert_eq!(state.handle_key("Home"), Some(true));
+    assert_eq!(state.handle_key("End"), Some(true));
+    assert_eq!(state.handle_key(" "), Some(false));
+    assert_eq!(state.handle_key("Tab"), None);
+    assert_eq!(state.selected(), Some(&"linux"));
+}
+
+#[test]
+fn space_selects_the_tab_stop_when_nothing_is_focused() {
+    let mut state = os().with_disabled("mac");
+    assert_eq!(state.handle_key(" "), Some(true));
+    assert_eq!(state.selected(), Some(&"windows"));
+}
@@ -0,0 +1,83 @@
+use radiors::{Align, Justify, Orientation, Size, Theme, Type, Variant, STYLESHEET};
+
+fn indent(css: &str) -> String {
+    css.lines().map(|line| format!("  {line}\n")).collect()
+}
+
+#[test]
+fn stylesheet_matches_the_light_and_dark_themes() {
+    assert!(STYLESHEET.contains(&Theme::light().to_css(":root")));
+    assert!(STYLESHEET.contains(&format!(
+        "@media (prefers-color-scheme: dark) {{\n{}}}",
+        indent(&Theme::dark().to_css(":root"))
+    )));
+}
+
+#[test]
+fn stylesheet_styles_every_class() {
+    let classes = [
+        Type::Primary.to_class(),
+        Type::Secondary.to_class(),
+        Type::Success.to_class(),
+        Type::Info.to_class(),
+        Type::Warning.to_class(),
+        Type::Danger.to_class(),
+        Size::XSmall.to_class(),
+        Size::Small.to_class(),
+        Size::Medium.to_class(),
+        Size::Large.to_class(),
+        Size::XLarge.to_class(),
+        Size::XXLarge.to_class(),
+    ]
+    .into_iter()
+    .chain(Orientation::Horizontal.to_class().split(' '))
+    .chain(Orientation::Vertical.to_class().split(' '))
+    .chain([
+        Size::XSmall.to_gap_class(),
+        Size::Small.to_gap_class(),
+        Size::Medium.to_gap_class(),
+        Size::Large.to_gap_class(),
+        Size::XLarge.to_gap_class(),
+        Size::XXLarge.to_gap_class(),
+        Align::Start.to_class(),
+        Align::Center.to_class(),
+        Align::End.to_class(),
+        Align::Stretch.to_class(),
+        Align::Baseline.to_class(),
+        Justify::Start.to_class(),
+        Justify::Center.to_class(),
+        Justify::End.to_class(),
+        Justify::SpaceBetween.to_class(),
+        Justify::SpaceAround.to_class(),
+        Justify::SpaceEvenly.to_class(),
+        "radiors-wrap",
+    ])
+    .chain([
+        "radiors-radio",
+        "radiors-radio--selected",
+        "radiors-radio__input",
+        "radiors-radio__description",
+        "radiors-radio__check",
+        Variant::Card.to_class(),
+        Variant::Segmented.to_class(),
+        "radiors-group--segmented",
+        "radiors-group__highlight",
+    ]);
+
+    for class in classes {
+        assert!(
+            STYLESHEET.contains(&format!(".{class} {{")),
+            "missing rule for `{class}`"
+        );
+    }
+}
+
+#[test]
+fn custom_variants_have_no_class() {
+    assert_eq!(Type::None.to_class(), "");
+    assert_eq!(Type::Custom("#123456").to_class(), "");
+    assert_eq!(Size::Custom("42px").to_class(), "");
+    assert_eq!(Size::Custom("42px").to_gap_class(), "");
+    assert_eq!(Size::Medium.to_class(), "radiors-size-md");
+    assert_eq!(Variant::Default.to_class(), "");
+}
@@ -0,0 +1,57 @@
+use radiors::{ColorScheme, Size, Swatch, Theme, Type};
+
+#[test]
+fn default_theme_matches_builtin_fallbacks() {
+    let style = Theme::default().to_style();
+    assert!(style.contains("--radiors-primary: #007bff; --radiors-on-primary: #ffffff;"));
+    assert!(Type::Primary
+        .to_style()
+        .contains("var(--radiors-primary, #007bff)"));
+    assert!(style.contains("--radiors-spacing-medium: 10px;"));
+    assert!(Size::Medium
+        .to_style()
+        .contains("var(--radiors-spacing-medium, 10px)"));
+}
+
+#[test]
+fn warning_uses_dark_text() {
+    assert!(Type::Warning
+        .to_style()
+        .contains("color: var(--radiors-on-warning, #212529);"));
+    assert_eq!(Theme::default().warning.foreground, "#212529");
+}
+
+#[test]
+fn custom_theme_emits_every_token() {
+    let theme = Theme {
+        danger: Swatch::new("crimson", "ivory"),
+        radius: "12px".into(),
+        focus_ring: "0 0 0 2px gold".into(),
+        selected_indicator: "inset 0 0 0 2px navy".into(),
+        ..Theme::default()
+    };
+    let style = theme.to_style();
+    assert!(style.contains("--radiors-danger: crimson; --radiors-on-danger: ivory;"));
+    assert!(style.contains("--radiors-radius: 12px;"));
+    assert!(style.contains("--radiors-focus-ring: 0 0 0 2px gold;"));
+    assert!(style.contains("--radiors-selected-indicator: inset 0 0 0 2px navy;"));
+}
+
+#[test]
+fn color_scheme_can_be_forced_or_follow_the_system() {
+    assert!(!ColorScheme::Light.is_dark(true));
+    assert!(ColorScheme::Dark.is_dark(false));
+    assert!(ColorScheme::System.is_dark(true));
+    assert!(!ColorScheme::System.is_dark(false));
+    assert_eq!(ColorScheme::default(), ColorScheme::System);
+}
+
+#[test]
+fn dark_theme_sets_a_surface_for_untyped_radios() {
+    let style = Theme::dark().to_style();
+    assert!(style.contains("--radiors-surface: #1f2937; --radiors-on-surface: #f9fafb;"));
+    assert!(Theme::light()
+        .to_style()
+        .contains("--radiors-surface: transparent; --radiors-on-surface: inherit;"));
+    assert_eq!(Theme::dark().radius, Theme::light().radius);
+}